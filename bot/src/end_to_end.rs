@@ -0,0 +1,119 @@
+//! Runs the whole bot against a fake Slack, with the real plugins
+
+use crate::with_plugins;
+use client::models::socket_message::{AddEmojiEvent, EmojiChangedEvent, Event};
+use fake_slack::{FakeSlack, BOT_USER_ID};
+use framework::SlackBot;
+use karma::entry::Entry;
+use karma::services::karma_repository::MockKarmaRepository;
+use std::future::{self, Future};
+use std::sync::Arc;
+
+/// Run the bot until `test` finishes, then stop it the way Slack would
+async fn run_bot_during(
+    slack: &FakeSlack,
+    karma_repository: MockKarmaRepository,
+    test: impl Future<Output = ()>,
+) {
+    let bot = with_plugins(
+        SlackBot::from_client(Arc::new(slack.client())),
+        Box::new(karma_repository),
+    );
+
+    let (result, _) = tokio::join!(bot.run(), async {
+        test.await;
+        slack.disable_socket_mode().await;
+    });
+
+    result.expect("Bot stopped with an error");
+}
+
+#[tokio::test]
+async fn should_reply_with_new_karma_when_someone_gets_karma() {
+    let slack = FakeSlack::start().await;
+    let mut karma_repository = MockKarmaRepository::new();
+    karma_repository
+        .expect_upsert_karma_change()
+        .times(1)
+        .returning(|_| Box::pin(future::ready(())));
+    karma_repository
+        .expect_get_karma_for()
+        .withf(|name| name == "rustacean")
+        .returning(|_| Box::pin(future::ready(Some(3))));
+
+    run_bot_during(&slack, karma_repository, async {
+        slack.send_message("C123", "U123", "rustacean++").await;
+
+        let posted = slack.wait_for_posted_messages(1).await;
+        assert_eq!(posted[0]["channel"], "C123");
+        assert_eq!(posted[0]["text"], ":upboat: rustacean: 3");
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn given_bot_mentioned_should_list_karma() {
+    let slack = FakeSlack::start().await;
+    let mut karma_repository = MockKarmaRepository::new();
+    karma_repository.expect_get_top().returning(|_| {
+        Box::pin(future::ready(vec![Entry {
+            id_name: "rustacean".to_string(),
+            display_name: "Rustacean".to_string(),
+            karma: 3,
+        }]))
+    });
+
+    run_bot_during(&slack, karma_repository, async {
+        let envelope_id = slack
+            .send_message("C123", "U123", &format!("<@{BOT_USER_ID}> karma list"))
+            .await;
+
+        slack.wait_for_acknowledgement(&envelope_id).await;
+        let posted = slack.wait_for_posted_messages(1).await;
+        assert_eq!(posted[0]["text"], "Rustacean: 3");
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn should_reply_to_spotify_links_in_thread() {
+    let slack = FakeSlack::start().await;
+
+    run_bot_during(&slack, MockKarmaRepository::new(), async {
+        slack
+            .send_message(
+                "C123",
+                "U123",
+                "https://open.spotify.com/track/4cOdK2wGLETKBW3PvgPWqT",
+            )
+            .await;
+
+        let posted = slack.wait_for_posted_messages(1).await;
+        assert_eq!(
+            posted[0]["text"],
+            "https://song.link/s/4cOdK2wGLETKBW3PvgPWqT"
+        );
+        assert!(posted[0]["thread_ts"].is_string());
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn should_announce_new_emoji() {
+    let slack = FakeSlack::start().await;
+
+    run_bot_during(&slack, MockKarmaRepository::new(), async {
+        slack
+            .send_event(Event::EmojiChanged(EmojiChangedEvent::Add(AddEmojiEvent {
+                id: "1700000000.000001".to_string().into(),
+                name: "ferris".to_string(),
+            })))
+            .await;
+
+        let posted = slack.wait_for_posted_messages(1).await;
+        // The fake has no channels to resolve the name with, so it's posted by name
+        assert_eq!(posted[0]["channel"], "#general");
+        assert_eq!(posted[0]["blocks"][0]["type"], "header");
+    })
+    .await;
+}
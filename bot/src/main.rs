@@ -1,24 +1,30 @@
+#[cfg(test)]
+mod end_to_end;
 mod plugins;
 
 use crate::plugins::emoji_changelog::EmojiChangelogPlugin;
 use crate::plugins::songlink::SongLinkPlugin;
+use client::events_api_listener::EventsApiListener;
 use config_file::FromConfigFile;
 use framework::SlackBot;
 use karma::services::karma_repository::{KarmaRepository, SqliteKarmaRepository};
 use karma::KarmaPlugin;
 use lazy_static::lazy_static;
 use serde::Deserialize;
+use std::time::Duration;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     activate_logging().unwrap();
-    SlackBot::new(&CONFIG.bot_token[..], &CONFIG.app_token[..])
-        .with_plugin(Box::new(SongLinkPlugin {}))
-        .with_plugin(Box::new(EmojiChangelogPlugin::new("#general".to_string())))
-        .with_dyn_service::<dyn KarmaRepository + Send + Sync>(Box::new(
-            SqliteKarmaRepository::default().await,
-        ))
-        .with_plugin(Box::<KarmaPlugin>::default())
+    let mut bot = SlackBot::new(&CONFIG.bot_token[..], &CONFIG.app_token[..]);
+    if let (Some(address), Some(signing_secret)) =
+        (&CONFIG.events_api_address, &CONFIG.signing_secret)
+    {
+        bot = bot.with_listener(Box::new(
+            EventsApiListener::bind(address.parse()?, signing_secret).await?,
+        ));
+    }
+    with_plugins(bot, Box::new(SqliteKarmaRepository::default().await))
         .run()
         .await
         .unwrap();
@@ -26,10 +32,25 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     Ok(())
 }
 
+/// Add termibot's plugins and the services they need
+fn with_plugins(
+    bot: SlackBot,
+    karma_repository: Box<dyn KarmaRepository + Send + Sync>,
+) -> SlackBot {
+    bot.with_directory(Duration::from_secs(60 * 60))
+        .with_plugin(Box::new(SongLinkPlugin {}))
+        .with_plugin(Box::new(EmojiChangelogPlugin::new("#general".to_string())))
+        .with_dyn_service::<dyn KarmaRepository + Send + Sync>(karma_repository)
+        .with_plugin(Box::<KarmaPlugin>::default())
+}
+
 #[derive(Deserialize)]
 pub struct Config {
     pub app_token: String,
     pub bot_token: String,
+    /// Receive events over HTTP on this address instead of socket mode, e.g. `0.0.0.0:3000`
+    pub events_api_address: Option<String>,
+    pub signing_secret: Option<String>,
 }
 
 lazy_static! {
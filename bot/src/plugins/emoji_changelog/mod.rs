@@ -1,10 +1,10 @@
 use async_trait::async_trait;
-use client::models::blocks::section::SectionBlock;
-use client::models::blocks::text::HeaderBlock;
+use client::blocks;
 use client::models::message_body::MessageBody;
 use client::models::socket_message::{EmojiChangedEvent, Event};
 use framework::actions::Action;
 use framework::dependencies::Dependencies;
+use framework::directory::Directory;
 use framework::plugins::Plugin;
 
 pub struct EmojiChangelogPlugin {
@@ -17,24 +17,38 @@ impl EmojiChangelogPlugin {
             notification_channel,
         }
     }
+
+    /// Resolve the notification channel's name to an ID, if a directory is available
+    async fn get_notification_channel(&self, dependencies: &Dependencies) -> String {
+        if let Some(directory) = dependencies.get::<Directory>() {
+            if let Some(id) = directory
+                .read()
+                .await
+                .channel_id(&self.notification_channel)
+                .await
+            {
+                return id;
+            }
+        }
+
+        self.notification_channel.clone()
+    }
 }
 
 #[async_trait]
 impl Plugin for EmojiChangelogPlugin {
-    async fn on_event(&self, event: &Event, _dependencies: &Dependencies) -> Vec<Action> {
+    async fn on_event(&self, event: &Event, dependencies: &Dependencies) -> Vec<Action> {
         match event {
             Event::EmojiChanged(emoji_event) => match emoji_event {
                 EmojiChangedEvent::Add(add_event) => {
                     let name = add_event.name.clone();
                     vec![Action::MessageChannel {
-                        channel: self.notification_channel.clone(),
+                        channel: self.get_notification_channel(dependencies).await,
                         message: MessageBody::new(
-                            vec![
-                                HeaderBlock::new("Emoji changelog"),
-                                SectionBlock::new_markdown(
-                                    &format!(":heavy_plus_sign: :{name}: `:{name}:`")[..],
-                                ),
-                            ],
+                            blocks! {
+                                header("Emoji changelog"),
+                                markdown(":heavy_plus_sign: :{name}: `:{name}:`"),
+                            },
                             None,
                         )
                         .unwrap(),
@@ -76,19 +90,20 @@ mod tests {
         let mut result = plugin.on_event(&event, &dependencies).await;
 
         assert_eq!(1, result.len());
+        let Action::MessageChannel { channel, message } = result.pop().unwrap() else {
+            panic!("Expected a message to the channel");
+        };
+        assert_eq!("#general", channel);
         assert_eq!(
-            Action::MessageChannel {
-                channel: "#general".to_string(),
-                message: MessageBody::new(
-                    vec![
-                        HeaderBlock::new("Emoji changelog"),
-                        SectionBlock::new_markdown(":heavy_plus_sign: :newmoji: `:newmoji:`")
-                    ],
-                    None
-                )
-                .unwrap(),
-            },
-            result.pop().unwrap()
+            MessageBody::new(
+                vec![
+                    HeaderBlock::new("Emoji changelog"),
+                    SectionBlock::new_markdown(":heavy_plus_sign: :newmoji: `:newmoji:`")
+                ],
+                None
+            )
+            .unwrap(),
+            message
         )
     }
 }
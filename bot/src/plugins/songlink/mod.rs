@@ -19,7 +19,7 @@ pub struct SongLinkPlugin {}
 impl Plugin for SongLinkPlugin {
     async fn on_event(&self, event: &Event, _dependencies: &Dependencies) -> Vec<Action> {
         match event {
-            Event::Message(message) => {
+            Event::Message(message) if message.is_new_message() => {
                 let text = message.text.clone().unwrap_or(String::new());
                 let captures = SPOTIFY_MATCHER.captures(&text[..]);
 
@@ -49,20 +49,20 @@ impl Plugin for SongLinkPlugin {
 mod tests {
     use super::*;
     use client::models::message_id::MessageId;
-    use client::models::socket_message::MessageEvent;
+    use client::models::socket_message::{MessageEvent, MessageSubtype};
     use framework::dependencies::DependenciesBuilder;
 
     #[tokio::test]
     async fn given_no_matching_url_do_nothing() {
         let dependencies = DependenciesBuilder::default().build();
-        let event = Event::Message(MessageEvent {
-            id: MessageId("myMessageId".to_string()),
+        let event = Event::Message(Box::new(MessageEvent {
             text: Some("test message".to_string()),
             user: None,
             blocks: None,
             channel: None,
             channel_type: None,
-        });
+            ..MessageEvent::new(MessageId("myMessageId".to_string()))
+        }));
 
         let result = SongLinkPlugin {}.on_event(&event, &dependencies).await;
 
@@ -72,25 +72,62 @@ mod tests {
     #[tokio::test]
     async fn given_spotify_link_should_respond_with_songlink_in_thread() {
         let dependencies = DependenciesBuilder::default().build();
-        let event = Event::Message(MessageEvent {
-            id: MessageId("myMessageId".to_string()),
+        let event = Event::Message(Box::new(MessageEvent {
             text: Some("https://open.spotify.com/track/0mjOx4zUlL5t4rF1xnrfvi".to_string()),
             user: None,
             blocks: None,
             channel: None,
             channel_type: None,
-        });
+            ..MessageEvent::new(MessageId("myMessageId".to_string()))
+        }));
 
         let mut result = SongLinkPlugin {}.on_event(&event, &dependencies).await;
 
         assert_eq!(1, result.len());
+        let Action::ReplyToThread {
+            channel,
+            thread_id,
+            message,
+        } = result.pop().unwrap()
+        else {
+            panic!("Expected a reply in the thread");
+        };
+        assert_eq!("", channel);
+        assert_eq!(MessageId::from("myMessageId"), thread_id);
         assert_eq!(
-            Action::ReplyToThread {
-                channel: "".to_string(),
-                thread_id: "myMessageId".to_string().into(),
-                message: MessageBody::from_text("https://song.link/s/0mjOx4zUlL5t4rF1xnrfvi"),
-            },
-            result.pop().unwrap()
+            MessageBody::from_text("https://song.link/s/0mjOx4zUlL5t4rF1xnrfvi"),
+            message
         )
     }
+
+    #[tokio::test]
+    async fn given_spotify_link_in_thread_broadcast_should_respond() {
+        let dependencies = DependenciesBuilder::default().build();
+        let event = Event::Message(Box::new(MessageEvent {
+            text: Some("https://open.spotify.com/track/0mjOx4zUlL5t4rF1xnrfvi".to_string()),
+            subtype: Some(MessageSubtype::ThreadBroadcast),
+            ..MessageEvent::new(MessageId("myMessageId".to_string()))
+        }));
+
+        let result = SongLinkPlugin {}.on_event(&event, &dependencies).await;
+
+        assert_eq!(1, result.len());
+    }
+
+    #[tokio::test]
+    async fn given_deleted_message_do_nothing() {
+        let dependencies = DependenciesBuilder::default().build();
+        let event = Event::Message(Box::new(MessageEvent {
+            text: Some("https://open.spotify.com/track/0mjOx4zUlL5t4rF1xnrfvi".to_string()),
+            subtype: Some(MessageSubtype::MessageDeleted {
+                deleted_ts: "1687458843.576569".into(),
+                previous_message: None,
+            }),
+            ..MessageEvent::new(MessageId("myMessageId".to_string()))
+        }));
+
+        let result = SongLinkPlugin {}.on_event(&event, &dependencies).await;
+
+        assert_eq!(0, result.len());
+    }
 }
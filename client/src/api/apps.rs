@@ -0,0 +1,14 @@
+use crate::api::{Encoding, SlackApiMethod, Token};
+use crate::models::websocket_url_message::WebsocketUrlMessage;
+use serde::Serialize;
+
+/// `apps.connections.open`, to get a URL to connect to socket mode with
+#[derive(Debug, Serialize)]
+pub struct ConnectionsOpenRequest;
+
+impl SlackApiMethod for ConnectionsOpenRequest {
+    type Response = WebsocketUrlMessage;
+    const NAME: &'static str = "apps.connections.open";
+    const TOKEN: Token = Token::App;
+    const ENCODING: Encoding = Encoding::Form;
+}
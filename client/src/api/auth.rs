@@ -0,0 +1,13 @@
+use crate::api::{Encoding, SlackApiMethod};
+use crate::models::auth_test_response::AuthTestResponse;
+use serde::Serialize;
+
+/// `auth.test`
+#[derive(Debug, Serialize)]
+pub struct TestRequest;
+
+impl SlackApiMethod for TestRequest {
+    type Response = AuthTestResponse;
+    const NAME: &'static str = "auth.test";
+    const ENCODING: Encoding = Encoding::Form;
+}
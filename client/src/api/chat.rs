@@ -0,0 +1,104 @@
+use crate::api::{Encoding, SlackApiMethod};
+use crate::models::blocks::Block;
+use crate::models::http_response::HttpApiResponse;
+use crate::models::message_id::MessageId;
+use crate::models::scheduled_message::{
+    ScheduleMessageResponse, ScheduledMessageId, ScheduledMessageListResponse,
+};
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+/// `chat.postMessage`, set `thread_ts` to reply to a thread
+#[skip_serializing_none]
+#[derive(Debug, Serialize)]
+pub struct PostMessageRequest<'a> {
+    pub channel: &'a str,
+    pub text: String,
+    pub blocks: Option<&'a [Block]>,
+    pub thread_ts: Option<&'a MessageId>,
+}
+
+impl SlackApiMethod for PostMessageRequest<'_> {
+    type Response = HttpApiResponse;
+    const NAME: &'static str = "chat.postMessage";
+}
+
+/// `chat.postEphemeral`
+#[derive(Debug, Serialize)]
+pub struct PostEphemeralRequest<'a> {
+    pub channel: &'a str,
+    pub user: &'a str,
+    pub text: String,
+    pub blocks: &'a [Block],
+}
+
+impl SlackApiMethod for PostEphemeralRequest<'_> {
+    type Response = HttpApiResponse;
+    const NAME: &'static str = "chat.postEphemeral";
+}
+
+/// `chat.scheduleMessage`
+#[derive(Debug, Serialize)]
+pub struct ScheduleMessageRequest<'a> {
+    pub channel: &'a str,
+    pub post_at: u64,
+    pub text: String,
+    pub blocks: &'a [Block],
+}
+
+impl SlackApiMethod for ScheduleMessageRequest<'_> {
+    type Response = ScheduleMessageResponse;
+    const NAME: &'static str = "chat.scheduleMessage";
+}
+
+/// `chat.scheduledMessages.list`
+#[skip_serializing_none]
+#[derive(Debug, Serialize)]
+pub struct ScheduledMessagesListRequest {
+    pub channel: Option<String>,
+    pub cursor: Option<String>,
+}
+
+impl SlackApiMethod for ScheduledMessagesListRequest {
+    type Response = ScheduledMessageListResponse;
+    const NAME: &'static str = "chat.scheduledMessages.list";
+    const ENCODING: Encoding = Encoding::Form;
+}
+
+/// `chat.deleteScheduledMessage`
+#[derive(Debug, Serialize)]
+pub struct DeleteScheduledMessageRequest<'a> {
+    pub channel: &'a str,
+    pub scheduled_message_id: &'a ScheduledMessageId,
+}
+
+impl SlackApiMethod for DeleteScheduledMessageRequest<'_> {
+    type Response = HttpApiResponse;
+    const NAME: &'static str = "chat.deleteScheduledMessage";
+}
+
+/// `chat.update`
+#[derive(Debug, Serialize)]
+pub struct UpdateRequest<'a> {
+    pub channel: &'a str,
+    pub ts: &'a MessageId,
+    pub text: String,
+    pub blocks: &'a [Block],
+}
+
+impl SlackApiMethod for UpdateRequest<'_> {
+    type Response = HttpApiResponse;
+    const NAME: &'static str = "chat.update";
+}
+
+/// `chat.delete`
+#[derive(Debug, Serialize)]
+pub struct DeleteRequest<'a> {
+    pub channel: &'a str,
+    pub ts: &'a MessageId,
+}
+
+impl SlackApiMethod for DeleteRequest<'_> {
+    type Response = HttpApiResponse;
+    const NAME: &'static str = "chat.delete";
+}
@@ -0,0 +1,61 @@
+use crate::api::{Encoding, SlackApiMethod};
+use crate::models::channel::{ChannelInfoResponse, ChannelListResponse};
+use crate::models::conversation_history::ConversationHistoryResponse;
+use crate::models::message_id::MessageId;
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+/// `conversations.history`
+#[skip_serializing_none]
+#[derive(Debug, Serialize)]
+pub struct HistoryRequest<'a> {
+    pub channel: &'a str,
+    pub cursor: Option<String>,
+}
+
+impl SlackApiMethod for HistoryRequest<'_> {
+    type Response = ConversationHistoryResponse;
+    const NAME: &'static str = "conversations.history";
+    const ENCODING: Encoding = Encoding::Form;
+}
+
+/// `conversations.replies`, `ts` is the thread's parent message
+#[skip_serializing_none]
+#[derive(Debug, Serialize)]
+pub struct RepliesRequest<'a> {
+    pub channel: &'a str,
+    pub ts: &'a MessageId,
+    pub cursor: Option<String>,
+}
+
+impl SlackApiMethod for RepliesRequest<'_> {
+    type Response = ConversationHistoryResponse;
+    const NAME: &'static str = "conversations.replies";
+    const ENCODING: Encoding = Encoding::Form;
+}
+
+/// `conversations.info`
+#[derive(Debug, Serialize)]
+pub struct InfoRequest<'a> {
+    pub channel: &'a str,
+}
+
+impl SlackApiMethod for InfoRequest<'_> {
+    type Response = ChannelInfoResponse;
+    const NAME: &'static str = "conversations.info";
+    const ENCODING: Encoding = Encoding::Form;
+}
+
+/// `conversations.list`, `types` is a comma separated list e.g. `public_channel,private_channel`
+#[skip_serializing_none]
+#[derive(Debug, Serialize)]
+pub struct ListRequest<'a> {
+    pub types: &'a str,
+    pub cursor: Option<String>,
+}
+
+impl SlackApiMethod for ListRequest<'_> {
+    type Response = ChannelListResponse;
+    const NAME: &'static str = "conversations.list";
+    const ENCODING: Encoding = Encoding::Form;
+}
@@ -0,0 +1,38 @@
+use crate::api::{Encoding, SlackApiMethod};
+use crate::models::file::{CompleteUploadResponse, UploadUrlResponse};
+use crate::models::message_id::MessageId;
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+/// `files.getUploadURLExternal`, `length` is the size of the file in bytes
+#[derive(Debug, Serialize)]
+pub struct GetUploadUrlExternalRequest<'a> {
+    pub filename: &'a str,
+    pub length: usize,
+}
+
+impl SlackApiMethod for GetUploadUrlExternalRequest<'_> {
+    type Response = UploadUrlResponse;
+    const NAME: &'static str = "files.getUploadURLExternal";
+    const ENCODING: Encoding = Encoding::Form;
+}
+
+/// `files.completeUploadExternal`, shares the uploaded files to a channel or thread
+#[skip_serializing_none]
+#[derive(Debug, Serialize)]
+pub struct CompleteUploadExternalRequest<'a> {
+    pub files: Vec<UploadedFile<'a>>,
+    pub channel_id: &'a str,
+    pub thread_ts: Option<&'a MessageId>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UploadedFile<'a> {
+    pub id: &'a str,
+    pub title: &'a str,
+}
+
+impl SlackApiMethod for CompleteUploadExternalRequest<'_> {
+    type Response = CompleteUploadResponse;
+    const NAME: &'static str = "files.completeUploadExternal";
+}
@@ -0,0 +1,67 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+pub mod apps;
+pub mod auth;
+pub mod chat;
+pub mod conversations;
+pub mod files;
+pub mod reactions;
+pub mod users;
+
+/// A Slack Web API method, e.g. `chat.postMessage`, and the request it takes.
+///
+/// The client wraps the methods the bot uses, but any other method can be called with
+/// [`crate::ReqwestSlackClient::call`] by implementing this for a request struct:
+///
+/// ```
+/// use client::api::SlackApiMethod;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize)]
+/// struct PinsAddRequest {
+///     channel: String,
+///     timestamp: String,
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct PinsAddResponse {}
+///
+/// impl SlackApiMethod for PinsAddRequest {
+///     type Response = PinsAddResponse;
+///     const NAME: &'static str = "pins.add";
+/// }
+/// ```
+pub trait SlackApiMethod: Serialize + Send + Sync {
+    /// Deserialised from Slack's response, once we know it is `"ok": true`
+    type Response: DeserializeOwned;
+    /// The method's name, as it appears in the URL
+    const NAME: &'static str;
+    /// The token Slack expects this method to be called with
+    const TOKEN: Token = Token::Bot;
+    /// How to send the request. Many methods that read from Slack only accept forms.
+    const ENCODING: Encoding = Encoding::Json;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Token {
+    /// The `xoxb-` token, used for almost everything
+    Bot,
+    /// The `xapp-` token, used to open socket mode connections
+    App,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Encoding {
+    Json,
+    /// `application/x-www-form-urlencoded`, leaving out any fields that are `None`
+    Form,
+}
+
+/// The part of every Web API response that says whether the call worked
+#[derive(Debug, Deserialize)]
+pub(crate) struct ApiStatus {
+    pub ok: bool,
+    pub error: Option<String>,
+    pub errors: Option<Vec<String>>,
+}
@@ -0,0 +1,30 @@
+use crate::api::SlackApiMethod;
+use crate::models::http_response::HttpApiResponse;
+use crate::models::message_id::MessageId;
+use serde::Serialize;
+
+/// `reactions.add`, `name` is the emoji without colons
+#[derive(Debug, Serialize)]
+pub struct AddRequest<'a> {
+    pub channel: &'a str,
+    pub timestamp: &'a MessageId,
+    pub name: &'a str,
+}
+
+impl SlackApiMethod for AddRequest<'_> {
+    type Response = HttpApiResponse;
+    const NAME: &'static str = "reactions.add";
+}
+
+/// `reactions.remove`, `name` is the emoji without colons
+#[derive(Debug, Serialize)]
+pub struct RemoveRequest<'a> {
+    pub channel: &'a str,
+    pub timestamp: &'a MessageId,
+    pub name: &'a str,
+}
+
+impl SlackApiMethod for RemoveRequest<'_> {
+    type Response = HttpApiResponse;
+    const NAME: &'static str = "reactions.remove";
+}
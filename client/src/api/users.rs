@@ -0,0 +1,29 @@
+use crate::api::{Encoding, SlackApiMethod};
+use crate::models::user::{UserInfoResponse, UserListResponse};
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+/// `users.info`
+#[derive(Debug, Serialize)]
+pub struct InfoRequest<'a> {
+    pub user: &'a str,
+}
+
+impl SlackApiMethod for InfoRequest<'_> {
+    type Response = UserInfoResponse;
+    const NAME: &'static str = "users.info";
+    const ENCODING: Encoding = Encoding::Form;
+}
+
+/// `users.list`
+#[skip_serializing_none]
+#[derive(Debug, Serialize)]
+pub struct ListRequest {
+    pub cursor: Option<String>,
+}
+
+impl SlackApiMethod for ListRequest {
+    type Response = UserListResponse;
+    const NAME: &'static str = "users.list";
+    const ENCODING: Encoding = Encoding::Form;
+}
@@ -1,45 +1,182 @@
+use crate::models::blocks::validation::ValidationError;
+use crate::rate_limiter::retry_after;
+use reqwest::header::HeaderMap;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
 use tokio::io;
 use tokio_tungstenite::tungstenite;
 use url::ParseError;
 
 #[derive(Debug)]
-pub struct SlackClientError(pub String);
+pub enum SlackClientError {
+    /// Couldn't talk to Slack over HTTP
+    Transport(String),
+    /// Couldn't open or use the socket mode connection
+    Websocket(String),
+    /// Slack sent us something we couldn't understand, or we couldn't build a request
+    Deserialisation(String),
+    /// Slack wants us to back off. `retry_after` is how long it asked us to wait, if it said.
+    RateLimited { retry_after: Option<Duration> },
+    /// Slack understood the request but refused it, e.g. `channel_not_found` or `not_in_channel`
+    Api { error: String, errors: Vec<String> },
+    /// The request was never sent because it was invalid
+    InvalidRequest(String),
+    /// The message was never sent because it breaks Block Kit's limits
+    InvalidMessage(Vec<ValidationError>),
+}
+
+impl SlackClientError {
+    /// Build the error for a response Slack sent with `"ok": false`
+    pub fn from_api(error: Option<String>, errors: Option<Vec<String>>) -> Self {
+        match error {
+            Some(error) if error == "ratelimited" => {
+                SlackClientError::RateLimited { retry_after: None }
+            }
+            Some(error) => SlackClientError::Api {
+                error,
+                errors: errors.unwrap_or_default(),
+            },
+            None => SlackClientError::Api {
+                error: String::from("unknown_error"),
+                errors: errors.unwrap_or_default(),
+            },
+        }
+    }
+
+    /// Build the error for an HTTP 429, using the `Retry-After` header Slack sends with it
+    pub fn rate_limited(headers: &HeaderMap) -> Self {
+        SlackClientError::RateLimited {
+            retry_after: retry_after(headers),
+        }
+    }
+
+    /// The error code Slack gave, if this is an API error
+    pub fn api_error(&self) -> Option<&str> {
+        match self {
+            SlackClientError::Api { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl Display for SlackClientError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SlackClientError::Transport(message) => write!(f, "HTTP error: {}", message),
+            SlackClientError::Websocket(message) => write!(f, "Websocket error: {}", message),
+            SlackClientError::Deserialisation(message) => {
+                write!(f, "Serde ([de]serialization) error: {}", message)
+            }
+            SlackClientError::RateLimited {
+                retry_after: Some(retry_after),
+            } => write!(f, "Rate limited, retry after {:?}", retry_after),
+            SlackClientError::RateLimited { retry_after: None } => write!(f, "Rate limited"),
+            SlackClientError::Api { error, errors } => {
+                write!(f, "{}: [{}]", error, errors.join(","))
+            }
+            SlackClientError::InvalidRequest(message) => write!(f, "Invalid request: {}", message),
+            SlackClientError::InvalidMessage(errors) => write!(
+                f,
+                "Invalid message: {}",
+                errors
+                    .iter()
+                    .map(ValidationError::to_string)
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SlackClientError {}
 
 impl From<reqwest_middleware::Error> for SlackClientError {
     fn from(value: reqwest_middleware::Error) -> Self {
-        SlackClientError(format!(
-            "Error with Reqest (HTTP) middleware (Rate limiting?) error: {}",
-            value
-        ))
+        match value {
+            reqwest_middleware::Error::Reqwest(err) => SlackClientError::from(err),
+            reqwest_middleware::Error::Middleware(err) => SlackClientError::Transport(format!(
+                "Error with Reqest (HTTP) middleware (Rate limiting?) error: {}",
+                err
+            )),
+        }
     }
 }
 
 impl From<reqwest::Error> for SlackClientError {
     fn from(value: reqwest::Error) -> Self {
-        SlackClientError(format!("Reqwest (HTTP) error: {}", value))
+        if value.is_decode() {
+            SlackClientError::Deserialisation(value.to_string())
+        } else {
+            SlackClientError::Transport(value.to_string())
+        }
     }
 }
 
 impl From<io::Error> for SlackClientError {
     fn from(value: io::Error) -> Self {
-        SlackClientError(format!("IO (TCP?) error: {}", value))
+        SlackClientError::Transport(format!("IO (TCP?) error: {}", value))
     }
 }
 
 impl From<tungstenite::Error> for SlackClientError {
     fn from(value: tungstenite::Error) -> Self {
-        SlackClientError(format!("Tungstenite (Websockets) error: {}", value))
+        SlackClientError::Websocket(value.to_string())
     }
 }
 
 impl From<serde_json::error::Error> for SlackClientError {
     fn from(value: serde_json::error::Error) -> Self {
-        SlackClientError(format!("Serde ([de]serialization) error: {}", value))
+        SlackClientError::Deserialisation(value.to_string())
     }
 }
 
 impl From<url::ParseError> for SlackClientError {
     fn from(value: ParseError) -> Self {
-        SlackClientError(format!("Error parsing websocket URL: {}", value))
+        SlackClientError::Websocket(format!("Error parsing websocket URL: {}", value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::{HeaderValue, RETRY_AFTER};
+
+    #[test]
+    fn should_keep_api_error_code_and_errors() {
+        let error = SlackClientError::from_api(
+            Some("invalid_blocks".to_string()),
+            Some(vec!["must be an array".to_string()]),
+        );
+
+        assert!(matches!(
+            error,
+            SlackClientError::Api { ref error, ref errors }
+                if error == "invalid_blocks" && errors == &vec!["must be an array".to_string()]
+        ));
+        assert_eq!(error.api_error(), Some("invalid_blocks"));
+    }
+
+    #[test]
+    fn given_ratelimited_error_code_should_be_rate_limited() {
+        let error = SlackClientError::from_api(Some("ratelimited".to_string()), None);
+
+        assert!(matches!(
+            error,
+            SlackClientError::RateLimited { retry_after: None }
+        ));
+    }
+
+    #[test]
+    fn should_read_retry_after_header() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("30"));
+
+        let error = SlackClientError::rate_limited(&headers);
+
+        assert!(matches!(
+            error,
+            SlackClientError::RateLimited { retry_after: Some(retry_after) }
+                if retry_after == Duration::from_secs(30)
+        ));
     }
 }
@@ -0,0 +1,436 @@
+use crate::error::SlackClientError;
+use crate::models::socket_message::{Payload, SocketMessage};
+use crate::recent_ids::RecentIds;
+use crate::socket_listener::SocketModeListener;
+use async_trait::async_trait;
+use hmac::{Hmac, Mac};
+use http_body::{LengthLimitError, Limited};
+use hyper::header::CONTENT_LENGTH;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use secrecy::{ExposeSecret, Secret};
+use serde::Deserialize;
+use sha2::Sha256;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::task::JoinHandle;
+use tracing::{error, info, warn};
+
+/// Requests older than this are rejected, so that captured requests can't be replayed
+const MAX_REQUEST_AGE_SECS: u64 = 60 * 5;
+/// How many event IDs to remember, to drop events Slack sends again when it retries
+const RECENT_EVENTS: usize = 1000;
+/// How many events can wait for the bot, beyond this Slack is asked to retry them later
+const BACKLOG: usize = 1000;
+/// Slack's requests are a few kilobytes, anything much bigger isn't from Slack
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+
+/// Receives events from Slack's Events API over HTTP, for when socket mode's outbound websocket
+/// connection isn't an option.
+///
+/// Point the app's event subscription request URL at this server, which checks every request was
+/// signed by Slack and answers the `url_verification` challenge Slack sends when the URL is set.
+///
+/// Slack retries events it doesn't get a response to within 3 seconds, so events are acknowledged
+/// as soon as they are queued for the bot rather than once the bot has received them. If the bot
+/// falls so far behind that the queue fills up, Slack is told to try again later. Retries of
+/// events that have already been queued are dropped.
+pub struct EventsApiListener {
+    receiver: mpsc::Receiver<SocketMessage>,
+    local_addr: SocketAddr,
+    server: JoinHandle<()>,
+}
+
+/// The bodies Slack posts to the request URL
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum EventsApiRequest {
+    UrlVerification {
+        challenge: String,
+    },
+    EventCallback(Box<Payload>),
+    #[serde(other)]
+    Other,
+}
+
+#[async_trait]
+impl SocketModeListener for EventsApiListener {
+    async fn next(&mut self) -> Result<SocketMessage, SlackClientError> {
+        self.receiver
+            .recv()
+            .await
+            .ok_or_else(|| SlackClientError::Transport("Events API server stopped".to_string()))
+    }
+}
+
+impl EventsApiListener {
+    /// Start listening on `addr`, checking requests were signed with the app's `signing_secret`
+    pub async fn bind(addr: SocketAddr, signing_secret: &str) -> Result<Self, SlackClientError> {
+        let (sender, receiver) = mpsc::channel(BACKLOG);
+        let signing_secret = Arc::new(Secret::new(signing_secret.to_string()));
+        let recent_events = Arc::new(Mutex::new(RecentIds::with_capacity(RECENT_EVENTS)));
+        let make_service = make_service_fn(move |_| {
+            let sender = sender.clone();
+            let signing_secret = signing_secret.clone();
+            let recent_events = recent_events.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    handle(
+                        request,
+                        sender.clone(),
+                        signing_secret.clone(),
+                        recent_events.clone(),
+                    )
+                }))
+            }
+        });
+        let server = Server::try_bind(&addr)
+            .map_err(|err| SlackClientError::Transport(err.to_string()))?
+            .serve(make_service);
+        let local_addr = server.local_addr();
+        info!("Listening for Events API requests on {}", local_addr);
+
+        Ok(EventsApiListener {
+            receiver,
+            local_addr,
+            server: tokio::spawn(async move {
+                if let Err(err) = server.await {
+                    error!("Events API server failed: {}", err);
+                }
+            }),
+        })
+    }
+
+    /// The address the server is listening on, useful when binding to port 0
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+}
+
+impl Drop for EventsApiListener {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+async fn handle(
+    request: Request<Body>,
+    sender: mpsc::Sender<SocketMessage>,
+    signing_secret: Arc<Secret<String>>,
+    recent_events: Arc<Mutex<RecentIds>>,
+) -> Result<Response<Body>, Infallible> {
+    if request.method() != Method::POST {
+        return Ok(respond(StatusCode::METHOD_NOT_ALLOWED, ""));
+    }
+    let timestamp = header(&request, "X-Slack-Request-Timestamp");
+    let signature = header(&request, "X-Slack-Signature");
+    let content_length = request
+        .headers()
+        .get(CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<usize>().ok());
+    if content_length.is_some_and(|length| length > MAX_BODY_BYTES) {
+        warn!(
+            "Rejected Events API request with a {:?} byte body",
+            content_length
+        );
+        return Ok(respond(StatusCode::PAYLOAD_TOO_LARGE, ""));
+    }
+    let body = Limited::new(request.into_body(), MAX_BODY_BYTES);
+    let body = match hyper::body::to_bytes(body).await {
+        Ok(body) => body,
+        Err(err) if err.is::<LengthLimitError>() => {
+            warn!(
+                "Rejected Events API request with a body over {} bytes",
+                MAX_BODY_BYTES
+            );
+            return Ok(respond(StatusCode::PAYLOAD_TOO_LARGE, ""));
+        }
+        Err(err) => {
+            warn!("Failed to read Events API request: {}", err);
+            return Ok(respond(StatusCode::BAD_REQUEST, ""));
+        }
+    };
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|now| now.as_secs())
+        .unwrap_or_default();
+    let is_signed = match (timestamp, signature) {
+        (Some(timestamp), Some(signature)) => is_signed_by_slack(
+            signing_secret.expose_secret(),
+            &timestamp,
+            &body,
+            &signature,
+            now,
+        ),
+        _ => false,
+    };
+    if !is_signed {
+        warn!("Rejected Events API request without a valid signature");
+        return Ok(respond(StatusCode::UNAUTHORIZED, ""));
+    }
+
+    match serde_json::from_slice(&body) {
+        Ok(EventsApiRequest::UrlVerification { challenge }) => {
+            info!("Answering Events API URL verification challenge");
+            Ok(respond(StatusCode::OK, &challenge))
+        }
+        Ok(EventsApiRequest::EventCallback(payload)) => {
+            // Held until the event is queued, so that a retry arriving meanwhile isn't queued too
+            let mut recent_events = recent_events.lock().expect("recent events lock poisoned");
+            let event_id = payload.event_id.clone();
+            if let Some(event_id) = &event_id {
+                if recent_events.contains(event_id.as_str()) {
+                    info!(
+                        "Dropping event {} which has already been received",
+                        event_id
+                    );
+                    return Ok(respond(StatusCode::OK, ""));
+                }
+            }
+            let message = SocketMessage::Event {
+                envelope_id: event_id.clone().unwrap_or_default(),
+                payload,
+            };
+            match sender.try_send(message) {
+                Ok(()) => {
+                    if let Some(event_id) = event_id {
+                        recent_events.insert(event_id);
+                    }
+                    Ok(respond(StatusCode::OK, ""))
+                }
+                Err(TrySendError::Full(_)) => {
+                    warn!("Too many events waiting for the bot, asking Slack to retry");
+                    Ok(respond(StatusCode::SERVICE_UNAVAILABLE, ""))
+                }
+                Err(TrySendError::Closed(_)) => Ok(respond(StatusCode::SERVICE_UNAVAILABLE, "")),
+            }
+        }
+        Ok(EventsApiRequest::Other) => Ok(respond(StatusCode::OK, "")),
+        Err(err) => {
+            // Still OK, otherwise Slack will keep retrying an event we'll never understand
+            warn!("Could not parse Events API request from Slack, probably an unsupported type not yet implemented. Caused by: `{}`", err);
+            Ok(respond(StatusCode::OK, ""))
+        }
+    }
+}
+
+fn header(request: &Request<Body>, name: &str) -> Option<String> {
+    request
+        .headers()
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+fn respond(status: StatusCode, body: &str) -> Response<Body> {
+    let mut response = Response::new(Body::from(body.to_string()));
+    *response.status_mut() = status;
+    response
+}
+
+/// Check `signature` is Slack's `X-Slack-Signature` for this request, and that it isn't stale.
+///
+/// See https://api.slack.com/authentication/verifying-requests-from-slack
+pub fn is_signed_by_slack(
+    signing_secret: &str,
+    timestamp: &str,
+    body: &[u8],
+    signature: &str,
+    now: u64,
+) -> bool {
+    let Ok(sent_at) = timestamp.parse::<u64>() else {
+        return false;
+    };
+    if now.abs_diff(sent_at) > MAX_REQUEST_AGE_SECS {
+        return false;
+    }
+    let Some(signature) = signature
+        .strip_prefix("v0=")
+        .and_then(|signature| hex::decode(signature).ok())
+    else {
+        return false;
+    };
+
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(signing_secret.as_bytes()) else {
+        return false;
+    };
+    mac.update(format!("v0:{}:", timestamp).as_bytes());
+    mac.update(body);
+    mac.verify_slice(&signature).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The example from Slack's documentation
+    const SIGNING_SECRET: &str = "8f742231b10e8888abcd99yyyzzz85a5";
+    const TIMESTAMP: &str = "1531420618";
+    const BODY: &str = "token=xyzz0WbapA4vBCDEFasx0q6G&team_id=T1DC2JH3J&team_domain=testteamnow&channel_id=G8PSS9T3V&channel_name=foobar&user_id=U2CERLKJA&user_name=roadrunner&command=%2Fwebhook-collect&text=&response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2FT1DC2JH3J%2F397700885554%2F96rGlfmibIGlgcZRskXaIFfN&trigger_id=398738663015.47445629121.803a0bc887a14d10d2c447fce8b6703c";
+    const SIGNATURE: &str = "v0=a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503";
+
+    #[test]
+    fn should_accept_slack_signature() {
+        assert!(is_signed_by_slack(
+            SIGNING_SECRET,
+            TIMESTAMP,
+            BODY.as_bytes(),
+            SIGNATURE,
+            1531420618
+        ));
+    }
+
+    #[test]
+    fn given_tampered_body_should_reject_signature() {
+        let body = BODY.replace("roadrunner", "coyote");
+
+        assert!(!is_signed_by_slack(
+            SIGNING_SECRET,
+            TIMESTAMP,
+            body.as_bytes(),
+            SIGNATURE,
+            1531420618
+        ));
+    }
+
+    #[test]
+    fn given_old_request_should_reject_signature() {
+        assert!(!is_signed_by_slack(
+            SIGNING_SECRET,
+            TIMESTAMP,
+            BODY.as_bytes(),
+            SIGNATURE,
+            1531420618 + 60 * 10
+        ));
+    }
+
+    const FAKE_EVENT_CALLBACK: &str = "{\"token\":\"F4K3T0K3N\",\"team_id\":\"F4K3T34M1D\",\"api_app_id\":\"F4K34P1ID\",\"event\":{\"client_msg_id\":\"fake-client-msg-id\",\"type\":\"message\",\"text\":\"test\",\"user\":\"F4K3USER1D\",\"ts\":\"1686321337.206879\",\"team\":\"F4K3T34M1D\",\"channel\":\"F4K3CH4NN3L1D\",\"event_ts\":\"1686321337.206879\",\"channel_type\":\"im\"},\"type\":\"event_callback\",\"event_id\":\"F4K33V3NT1D\",\"event_time\":1686321337,\"authorizations\":[{\"enterprise_id\":null,\"team_id\":\"F4K3T34M1D\",\"user_id\":\"F4K3USER1D\",\"is_bot\":true,\"is_enterprise_install\":false}]}";
+
+    fn signed_request(body: &str) -> Request<Body> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            .to_string();
+        let mut mac = Hmac::<Sha256>::new_from_slice(SIGNING_SECRET.as_bytes()).unwrap();
+        mac.update(format!("v0:{}:{}", timestamp, body).as_bytes());
+        let signature = format!("v0={}", hex::encode(mac.finalize().into_bytes()));
+
+        Request::post("/slack/events")
+            .header("X-Slack-Request-Timestamp", timestamp)
+            .header("X-Slack-Signature", signature)
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    async fn handle_with(
+        body: &str,
+        sender: &mpsc::Sender<SocketMessage>,
+        recent_events: &Arc<Mutex<RecentIds>>,
+    ) -> StatusCode {
+        handle(
+            signed_request(body),
+            sender.clone(),
+            Arc::new(Secret::new(SIGNING_SECRET.to_string())),
+            recent_events.clone(),
+        )
+        .await
+        .unwrap()
+        .status()
+    }
+
+    #[tokio::test]
+    async fn given_backlog_is_full_should_ask_slack_to_retry() {
+        let (sender, mut receiver) = mpsc::channel(1);
+        let recent_events = Arc::new(Mutex::new(RecentIds::with_capacity(RECENT_EVENTS)));
+        let second_event = FAKE_EVENT_CALLBACK.replace("F4K33V3NT1D", "S3C0ND3V3NT1D");
+
+        assert_eq!(
+            handle_with(FAKE_EVENT_CALLBACK, &sender, &recent_events).await,
+            StatusCode::OK
+        );
+        assert_eq!(
+            handle_with(&second_event, &sender, &recent_events).await,
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+
+        receiver.recv().await.unwrap();
+        assert_eq!(
+            handle_with(&second_event, &sender, &recent_events).await,
+            StatusCode::OK
+        );
+        assert!(matches!(
+            receiver.recv().await.unwrap(),
+            SocketMessage::Event { envelope_id, .. } if envelope_id == "S3C0ND3V3NT1D"
+        ));
+    }
+
+    #[tokio::test]
+    async fn given_bot_has_stopped_should_not_remember_event() {
+        let (sender, receiver) = mpsc::channel(1);
+        let recent_events = Arc::new(Mutex::new(RecentIds::with_capacity(RECENT_EVENTS)));
+        drop(receiver);
+
+        assert_eq!(
+            handle_with(FAKE_EVENT_CALLBACK, &sender, &recent_events).await,
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+        assert!(!recent_events.lock().unwrap().contains("F4K33V3NT1D"));
+    }
+
+    #[tokio::test]
+    async fn given_large_content_length_should_reject_request() {
+        let (sender, _receiver) = mpsc::channel(1);
+        let mut request = signed_request(FAKE_EVENT_CALLBACK);
+        request
+            .headers_mut()
+            .insert(CONTENT_LENGTH, (MAX_BODY_BYTES + 1).into());
+
+        let response = handle(
+            request,
+            sender,
+            Arc::new(Secret::new(SIGNING_SECRET.to_string())),
+            Arc::new(Mutex::new(RecentIds::with_capacity(RECENT_EVENTS))),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[tokio::test]
+    async fn given_large_body_should_reject_request() {
+        let (sender, _receiver) = mpsc::channel(1);
+        let body = "x".repeat(MAX_BODY_BYTES + 1);
+
+        assert_eq!(
+            handle_with(
+                &body,
+                &sender,
+                &Arc::new(Mutex::new(RecentIds::with_capacity(1)))
+            )
+            .await,
+            StatusCode::PAYLOAD_TOO_LARGE
+        );
+    }
+
+    #[test]
+    fn should_parse_url_verification_request() {
+        let request: EventsApiRequest = serde_json::from_str(
+            "{\"token\":\"Jhj5dZrVaK7ZwHHjRyZWjbDl\",\"challenge\":\"3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P\",\"type\":\"url_verification\"}",
+        )
+        .unwrap();
+
+        assert!(matches!(
+            request,
+            EventsApiRequest::UrlVerification { challenge }
+                if challenge == "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P"
+        ));
+    }
+}
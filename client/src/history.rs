@@ -0,0 +1,147 @@
+use crate::error::SlackClientError;
+use crate::models::conversation_history::MessagePage;
+use crate::models::message_id::MessageId;
+use crate::models::socket_message::MessageEvent;
+use crate::SlackClient;
+use futures_util::{future, stream, Stream, StreamExt};
+use std::future::Future;
+use std::sync::Arc;
+
+enum Cursor {
+    Start,
+    Next(String),
+    End,
+}
+
+/// Stream every message in a channel, newest first, fetching further pages as needed.
+///
+/// If fetching a page fails the error is yielded and the stream ends.
+pub fn conversation_history(
+    client: Arc<dyn SlackClient + Send + Sync>,
+    channel: &str,
+) -> impl Stream<Item = Result<MessageEvent, SlackClientError>> + Send {
+    let channel = channel.to_string();
+    paginate(move |cursor| {
+        let client = client.clone();
+        let channel = channel.clone();
+        async move { client.get_conversation_history(&channel, cursor).await }
+    })
+}
+
+/// Stream every message in a thread, oldest first, starting with the parent message.
+///
+/// If fetching a page fails the error is yielded and the stream ends.
+pub fn thread_replies(
+    client: Arc<dyn SlackClient + Send + Sync>,
+    channel: &str,
+    parent: &MessageId,
+) -> impl Stream<Item = Result<MessageEvent, SlackClientError>> + Send {
+    let channel = channel.to_string();
+    let parent = parent.clone();
+    paginate(move |cursor| {
+        let client = client.clone();
+        let channel = channel.clone();
+        let parent = parent.clone();
+        async move { client.get_thread_replies(&channel, &parent, cursor).await }
+    })
+}
+
+fn paginate<F, Fut>(fetch: F) -> impl Stream<Item = Result<MessageEvent, SlackClientError>> + Send
+where
+    F: Fn(Option<String>) -> Fut + Send,
+    Fut: Future<Output = Result<MessagePage, SlackClientError>> + Send,
+{
+    stream::unfold(Cursor::Start, move |cursor| {
+        let page = match cursor {
+            Cursor::Start => Some(fetch(None)),
+            Cursor::Next(next_cursor) => Some(fetch(Some(next_cursor))),
+            Cursor::End => None,
+        };
+
+        async move {
+            match page?.await {
+                Ok(page) => {
+                    let cursor = page.next_cursor.map_or(Cursor::End, Cursor::Next);
+                    let messages = stream::iter(page.messages.into_iter().map(Ok));
+                    Some((messages.left_stream(), cursor))
+                }
+                Err(err) => {
+                    let error = stream::once(future::ready(Err(err)));
+                    Some((error.right_stream(), Cursor::End))
+                }
+            }
+        }
+    })
+    .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MockSlackClient;
+
+    fn message(id: &str) -> MessageEvent {
+        MessageEvent {
+            text: Some(id.to_string()),
+            user: None,
+            blocks: None,
+            channel: Some("C2J0AD99Q".to_string()),
+            channel_type: None,
+            ..MessageEvent::new(id.into())
+        }
+    }
+
+    #[tokio::test]
+    async fn should_follow_cursor_until_last_page() {
+        let mut client = MockSlackClient::new();
+        client
+            .expect_get_conversation_history()
+            .withf(|channel, cursor| channel == "C2J0AD99Q" && cursor.is_none())
+            .times(1)
+            .returning(|_, _| {
+                Ok(MessagePage {
+                    messages: vec![message("1"), message("2")],
+                    next_cursor: Some("page-2".to_string()),
+                })
+            });
+        client
+            .expect_get_conversation_history()
+            .withf(|_, cursor| cursor.as_deref() == Some("page-2"))
+            .times(1)
+            .returning(|_, _| {
+                Ok(MessagePage {
+                    messages: vec![message("3")],
+                    next_cursor: None,
+                })
+            });
+
+        let result: Vec<MessageEvent> = conversation_history(Arc::new(client), "C2J0AD99Q")
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        assert_eq!(result, vec![message("1"), message("2"), message("3")]);
+    }
+
+    #[tokio::test]
+    async fn given_error_fetching_page_should_yield_error_and_end() {
+        let mut client = MockSlackClient::new();
+        client
+            .expect_get_thread_replies()
+            .times(1)
+            .returning(|_, _, _| {
+                Err(SlackClientError::Api {
+                    error: "thread_not_found".to_string(),
+                    errors: vec![],
+                })
+            });
+
+        let result: Vec<Result<MessageEvent, SlackClientError>> =
+            thread_replies(Arc::new(client), "C2J0AD99Q", &"1".into())
+                .collect()
+                .await;
+
+        assert_eq!(result.len(), 1);
+        assert!(result[0].is_err());
+    }
+}
@@ -1,21 +1,38 @@
+use crate::api::{
+    apps, auth, chat, conversations, files, reactions, users, ApiStatus, Encoding, SlackApiMethod,
+    Token,
+};
 use crate::models::auth_test_response::AuthTestResponse;
+use crate::models::channel::{Channel, ChannelListResponse, ChannelPage};
+use crate::models::conversation_history::MessagePage;
+use crate::models::file::File;
 use crate::models::message_body::MessageBody;
 use crate::models::message_id::MessageId;
-use crate::models::websocket_url_message::WebsocketUrlMessage;
+use crate::models::scheduled_message::{
+    ScheduledMessageId, ScheduledMessageListResponse, ScheduledMessagePage,
+};
+use crate::models::user::{User, UserListResponse, UserPage};
 use crate::rate_limiter::RateLimitingMiddleware;
 use async_trait::async_trait;
 use error::SlackClientError;
 use mockall::automock;
 use models::http_response::HttpApiResponse;
-use reqwest::Client;
+use reqwest::{Client, StatusCode};
 use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
 use secrecy::{ExposeSecret, Secret};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
 use tracing::info;
 use url::Url;
 
+pub mod api;
 pub mod error;
+pub mod events_api_listener;
+pub mod history;
 pub mod models;
+pub mod multi_socket_listener;
 pub mod rate_limiter;
+pub mod recent_ids;
 pub mod socket_listener;
 
 /// A client for talking to the Slack API
@@ -42,6 +59,124 @@ pub trait SlackClient {
         message: &MessageBody,
     ) -> Result<HttpApiResponse, SlackClientError>;
 
+    /// Send a message to a channel that only `user` can see.
+    ///
+    /// Ephemeral messages are not persisted, and can only be sent to users who are in the channel.
+    async fn message_ephemeral(
+        &self,
+        channel: &str,
+        user: &str,
+        message: &MessageBody,
+    ) -> Result<HttpApiResponse, SlackClientError>;
+
+    /// Schedule a message to be sent to a channel at `post_at`, a Unix timestamp.
+    ///
+    /// Slack will only schedule messages up to 120 days in the future.
+    async fn schedule_message(
+        &self,
+        channel: &str,
+        post_at: u64,
+        message: &MessageBody,
+    ) -> Result<ScheduledMessageId, SlackClientError>;
+
+    /// Get a page of messages that are scheduled but not yet sent, optionally only for one channel.
+    ///
+    /// Pass the `next_cursor` of the previous page to get the page after it.
+    async fn list_scheduled_messages(
+        &self,
+        channel: Option<String>,
+        cursor: Option<String>,
+    ) -> Result<ScheduledMessagePage, SlackClientError>;
+
+    /// Cancel a scheduled message before it is sent
+    async fn delete_scheduled_message(
+        &self,
+        channel: &str,
+        scheduled_message_id: &ScheduledMessageId,
+    ) -> Result<HttpApiResponse, SlackClientError>;
+
+    /// Edit a message that was previously sent by the bot.
+    ///
+    /// The message to edit is specified with `message_id`, its content is replaced by `message`.
+    async fn update_message(
+        &self,
+        channel: &str,
+        message_id: &MessageId,
+        message: &MessageBody,
+    ) -> Result<HttpApiResponse, SlackClientError>;
+
+    /// Delete a message that was previously sent by the bot.
+    async fn delete_message(
+        &self,
+        channel: &str,
+        message_id: &MessageId,
+    ) -> Result<HttpApiResponse, SlackClientError>;
+
+    /// React to a message with an emoji.
+    ///
+    /// The emoji is specified by name, surrounding colons are optional.
+    async fn add_reaction(
+        &self,
+        channel: &str,
+        message_id: &MessageId,
+        emoji: &str,
+    ) -> Result<HttpApiResponse, SlackClientError>;
+
+    /// Remove a reaction the bot previously added to a message.
+    async fn remove_reaction(
+        &self,
+        channel: &str,
+        message_id: &MessageId,
+        emoji: &str,
+    ) -> Result<HttpApiResponse, SlackClientError>;
+
+    /// Get a page of messages from a channel, newest first.
+    ///
+    /// Pass the `next_cursor` of the previous page to get the page after it.
+    /// See [`history::conversation_history`] for a stream that follows the cursor automatically.
+    async fn get_conversation_history(
+        &self,
+        channel: &str,
+        cursor: Option<String>,
+    ) -> Result<MessagePage, SlackClientError>;
+
+    /// Get a page of replies to a thread, oldest first. The parent message is always included.
+    ///
+    /// See [`history::thread_replies`] for a stream that follows the cursor automatically.
+    async fn get_thread_replies(
+        &self,
+        channel: &str,
+        parent: &MessageId,
+        cursor: Option<String>,
+    ) -> Result<MessagePage, SlackClientError>;
+
+    /// Get a user's profile by their ID
+    async fn get_user(&self, user_id: &str) -> Result<User, SlackClientError>;
+
+    /// Get a page of all the users in the workspace.
+    ///
+    /// Pass the `next_cursor` of the previous page to get the page after it.
+    async fn list_users(&self, cursor: Option<String>) -> Result<UserPage, SlackClientError>;
+
+    /// Get a channel's details by its ID
+    async fn get_channel(&self, channel_id: &str) -> Result<Channel, SlackClientError>;
+
+    /// Get a page of all the public and private channels the bot can see.
+    ///
+    /// Pass the `next_cursor` of the previous page to get the page after it.
+    async fn list_channels(&self, cursor: Option<String>) -> Result<ChannelPage, SlackClientError>;
+
+    /// Upload a file and share it to a channel, or to a thread if `thread` is given.
+    ///
+    /// Unlike messages, `channel` must be a channel ID rather than a name.
+    async fn upload_file(
+        &self,
+        channel: &str,
+        thread: Option<MessageId>,
+        filename: &str,
+        content: Vec<u8>,
+    ) -> Result<File, SlackClientError>;
+
     /// Get a URL for opening a new Websocket connection
     async fn get_websocket_url(&self) -> Result<Url, SlackClientError>;
 }
@@ -52,8 +187,11 @@ pub struct ReqwestSlackClient {
     bot_token: Secret<String>,
     app_token: Secret<String>,
     http: ClientWithMiddleware,
+    base_url: String,
 }
 
+const SLACK_API_URL: &str = "https://slack.com/api/";
+
 impl ReqwestSlackClient {
     pub fn new(bot_token: &str, app_token: &str) -> ReqwestSlackClient {
         ReqwestSlackClient {
@@ -62,6 +200,7 @@ impl ReqwestSlackClient {
             http: ClientBuilder::new(Client::new())
                 .with(RateLimitingMiddleware::new())
                 .build(),
+            base_url: SLACK_API_URL.to_string(),
         }
     }
 
@@ -74,55 +213,59 @@ impl ReqwestSlackClient {
             bot_token: Secret::new(String::from(bot_token)),
             app_token: Secret::new(String::from(app_token)),
             http: client,
+            base_url: SLACK_API_URL.to_string(),
         }
     }
 
-    fn ensure_correct_result_type_because_slack_stupidly_uses_200_status_for_errors(
-        result: Result<HttpApiResponse, SlackClientError>,
-    ) -> Result<HttpApiResponse, SlackClientError> {
-        if let Ok(response) = result {
-            if !response.ok {
-                if response.error.is_none() && response.errors.is_none() {
-                    Err(SlackClientError(
-                        "Slack returned not-okay result but no errors".to_string(),
-                    ))
-                } else {
-                    let err_type = response
-                        .error
-                        .unwrap_or("No error type provided".to_string());
-                    let errors = response
-                        .errors
-                        .unwrap_or(vec![])
-                        .into_iter()
-                        .reduce(|acc, err| format!("{},{}", acc, err))
-                        .unwrap_or(String::new());
-                    Err(SlackClientError(format!("{}: [{}]", err_type, errors)))
-                }
-            } else {
-                Ok(response)
-            }
-        } else {
-            result
+    /// Send requests somewhere other than `https://slack.com/api/`, e.g. a fake Slack in tests.
+    ///
+    /// Method names are appended to `base_url`, so it should end with `/api/` for rate limiting
+    /// to recognise them.
+    pub fn with_base_url(mut self, base_url: &str) -> ReqwestSlackClient {
+        self.base_url = base_url.to_string();
+        self
+    }
+
+    /// Call a Web API method, see [`SlackApiMethod`] to add methods the client doesn't wrap.
+    ///
+    /// Slack's `"ok": false` responses are returned as [`SlackClientError::Api`].
+    pub async fn call<M: SlackApiMethod>(
+        &self,
+        request: M,
+    ) -> Result<M::Response, SlackClientError> {
+        let token = match M::TOKEN {
+            Token::Bot => &self.bot_token,
+            Token::App => &self.app_token,
+        };
+        let builder = self
+            .http
+            .post(format!("{}{}", self.base_url, M::NAME))
+            .header("Authorization", format!("Bearer {}", token.expose_secret()))
+            .header("User-Agent", "slackbot-client")
+            .header("Accept", "application/json");
+        let builder = match M::ENCODING {
+            // Via `Value` so the keys are always sorted, which keeps test recordings stable
+            Encoding::Json => builder.json(&serde_json::to_value(&request)?),
+            Encoding::Form => builder.form(&request),
+        };
+
+        let response = builder
+            .send()
+            .await?
+            .slack_json::<serde_json::Value>()
+            .await?;
+        let status = ApiStatus::deserialize(&response)?;
+        if !status.ok {
+            return Err(SlackClientError::from_api(status.error, status.errors));
         }
+        Ok(M::Response::deserialize(response)?)
     }
 }
 
 #[async_trait]
 impl SlackClient for ReqwestSlackClient {
     async fn get_identity(&self) -> Result<AuthTestResponse, SlackClientError> {
-        self.http
-            .post("https://slack.com/api/auth.test")
-            .header(
-                "Authorization",
-                format!("Bearer {}", self.bot_token.expose_secret()),
-            )
-            .header("User-Agent", "slackbot-client")
-            .header("Accept", "application/json")
-            .send()
-            .await?
-            .json::<AuthTestResponse>()
-            .await
-            .map_err(SlackClientError::from)
+        self.call(auth::TestRequest).await
     }
 
     #[tracing::instrument]
@@ -131,30 +274,14 @@ impl SlackClient for ReqwestSlackClient {
         channel: &str,
         body: &MessageBody,
     ) -> Result<HttpApiResponse, SlackClientError> {
-        let body = serde_json::json!({
-            "channel": channel,
-            "text": body.get_text(),
-            "blocks": body.get_blocks()
-        });
         info!("Messaging channel {} with {:?}", channel, body);
-
-        let result = self
-            .http
-            .post("https://slack.com/api/chat.postMessage")
-            .header(
-                "Authorization",
-                format!("Bearer {}", self.bot_token.expose_secret()),
-            )
-            .header("User-Agent", "slackbot-client")
-            .header("Accept", "application/json")
-            .json(&body)
-            .send()
-            .await?
-            .json::<HttpApiResponse>()
-            .await
-            .map_err(SlackClientError::from);
-
-        Self::ensure_correct_result_type_because_slack_stupidly_uses_200_status_for_errors(result)
+        self.call(chat::PostMessageRequest {
+            channel,
+            text: body.get_text(),
+            blocks: Some(body.get_blocks()),
+            thread_ts: None,
+        })
+        .await
     }
 
     /// Send a reply to a thread.
@@ -171,47 +298,308 @@ impl SlackClient for ReqwestSlackClient {
             "Messaging channel {}, thread {:?} with {:?}",
             channel, parent, body
         );
-        let result = self
-            .http
-            .post("https://slack.com/api/chat.postMessage")
-            .header(
-                "Authorization",
-                format!("Bearer {}", self.bot_token.expose_secret()),
-            )
-            .header("User-Agent", "slackbot-client")
-            .header("Accept", "application/json")
-            .json(&serde_json::json!({
-                "channel": channel,
-                "thread_ts": parent,
-                "text": body.get_text()
-            }))
-            .send()
-            .await?
-            .json::<HttpApiResponse>()
+        self.call(chat::PostMessageRequest {
+            channel,
+            text: body.get_text(),
+            blocks: None,
+            thread_ts: Some(parent),
+        })
+        .await
+    }
+
+    #[tracing::instrument]
+    async fn message_ephemeral(
+        &self,
+        channel: &str,
+        user: &str,
+        body: &MessageBody,
+    ) -> Result<HttpApiResponse, SlackClientError> {
+        info!(
+            "Messaging user {} in channel {} with {:?}",
+            user, channel, body
+        );
+        self.call(chat::PostEphemeralRequest {
+            channel,
+            user,
+            text: body.get_text(),
+            blocks: body.get_blocks(),
+        })
+        .await
+    }
+
+    #[tracing::instrument]
+    async fn schedule_message(
+        &self,
+        channel: &str,
+        post_at: u64,
+        body: &MessageBody,
+    ) -> Result<ScheduledMessageId, SlackClientError> {
+        info!(
+            "Scheduling message to channel {} at {} with {:?}",
+            channel, post_at, body
+        );
+        self.call(chat::ScheduleMessageRequest {
+            channel,
+            post_at,
+            text: body.get_text(),
+            blocks: body.get_blocks(),
+        })
+        .await
+        .map(|response| response.scheduled_message_id)
+    }
+
+    #[tracing::instrument]
+    async fn list_scheduled_messages(
+        &self,
+        channel: Option<String>,
+        cursor: Option<String>,
+    ) -> Result<ScheduledMessagePage, SlackClientError> {
+        info!(
+            "Listing scheduled messages for channel {:?} from cursor {:?}",
+            channel, cursor
+        );
+        self.call(chat::ScheduledMessagesListRequest { channel, cursor })
             .await
-            .map_err(SlackClientError::from);
+            .map(ScheduledMessageListResponse::into_page)
+    }
+
+    #[tracing::instrument]
+    async fn delete_scheduled_message(
+        &self,
+        channel: &str,
+        scheduled_message_id: &ScheduledMessageId,
+    ) -> Result<HttpApiResponse, SlackClientError> {
+        info!(
+            "Deleting scheduled message {:?} in channel {}",
+            scheduled_message_id, channel
+        );
+        self.call(chat::DeleteScheduledMessageRequest {
+            channel,
+            scheduled_message_id,
+        })
+        .await
+    }
 
-        Self::ensure_correct_result_type_because_slack_stupidly_uses_200_status_for_errors(result)
+    #[tracing::instrument]
+    async fn update_message(
+        &self,
+        channel: &str,
+        message_id: &MessageId,
+        body: &MessageBody,
+    ) -> Result<HttpApiResponse, SlackClientError> {
+        info!(
+            "Updating message {:?} in channel {} with {:?}",
+            message_id, channel, body
+        );
+        self.call(chat::UpdateRequest {
+            channel,
+            ts: message_id,
+            text: body.get_text(),
+            blocks: body.get_blocks(),
+        })
+        .await
     }
 
     #[tracing::instrument]
-    async fn get_websocket_url(&self) -> Result<Url, SlackClientError> {
-        info!("Connecting to socket mode");
+    async fn delete_message(
+        &self,
+        channel: &str,
+        message_id: &MessageId,
+    ) -> Result<HttpApiResponse, SlackClientError> {
+        info!("Deleting message {:?} in channel {}", message_id, channel);
+        self.call(chat::DeleteRequest {
+            channel,
+            ts: message_id,
+        })
+        .await
+    }
+
+    #[tracing::instrument]
+    async fn add_reaction(
+        &self,
+        channel: &str,
+        message_id: &MessageId,
+        emoji: &str,
+    ) -> Result<HttpApiResponse, SlackClientError> {
+        info!(
+            "Reacting to message {:?} in channel {} with {}",
+            message_id, channel, emoji
+        );
+        self.call(reactions::AddRequest {
+            channel,
+            timestamp: message_id,
+            name: emoji.trim_matches(':'),
+        })
+        .await
+    }
+
+    #[tracing::instrument]
+    async fn remove_reaction(
+        &self,
+        channel: &str,
+        message_id: &MessageId,
+        emoji: &str,
+    ) -> Result<HttpApiResponse, SlackClientError> {
+        info!(
+            "Removing reaction {} from message {:?} in channel {}",
+            emoji, message_id, channel
+        );
+        self.call(reactions::RemoveRequest {
+            channel,
+            timestamp: message_id,
+            name: emoji.trim_matches(':'),
+        })
+        .await
+    }
+
+    #[tracing::instrument]
+    async fn get_conversation_history(
+        &self,
+        channel: &str,
+        cursor: Option<String>,
+    ) -> Result<MessagePage, SlackClientError> {
+        info!(
+            "Getting history of channel {} from cursor {:?}",
+            channel, cursor
+        );
+        self.call(conversations::HistoryRequest { channel, cursor })
+            .await
+            .map(|response| response.into_page(channel))
+    }
+
+    #[tracing::instrument]
+    async fn get_thread_replies(
+        &self,
+        channel: &str,
+        parent: &MessageId,
+        cursor: Option<String>,
+    ) -> Result<MessagePage, SlackClientError> {
+        info!(
+            "Getting replies to thread {:?} in channel {} from cursor {:?}",
+            parent, channel, cursor
+        );
+        self.call(conversations::RepliesRequest {
+            channel,
+            ts: parent,
+            cursor,
+        })
+        .await
+        .map(|response| response.into_page(channel))
+    }
+
+    #[tracing::instrument]
+    async fn get_user(&self, user_id: &str) -> Result<User, SlackClientError> {
+        info!("Getting user {}", user_id);
+        self.call(users::InfoRequest { user: user_id })
+            .await
+            .map(|response| response.user)
+    }
+
+    #[tracing::instrument]
+    async fn list_users(&self, cursor: Option<String>) -> Result<UserPage, SlackClientError> {
+        info!("Listing users from cursor {:?}", cursor);
+        self.call(users::ListRequest { cursor })
+            .await
+            .map(UserListResponse::into_page)
+    }
+
+    #[tracing::instrument]
+    async fn get_channel(&self, channel_id: &str) -> Result<Channel, SlackClientError> {
+        info!("Getting channel {}", channel_id);
+        self.call(conversations::InfoRequest {
+            channel: channel_id,
+        })
+        .await
+        .map(|response| response.channel)
+    }
+
+    #[tracing::instrument]
+    async fn list_channels(&self, cursor: Option<String>) -> Result<ChannelPage, SlackClientError> {
+        info!("Listing channels from cursor {:?}", cursor);
+        self.call(conversations::ListRequest {
+            types: "public_channel,private_channel",
+            cursor,
+        })
+        .await
+        .map(ChannelListResponse::into_page)
+    }
+
+    #[tracing::instrument(skip(content))]
+    async fn upload_file(
+        &self,
+        channel: &str,
+        thread: Option<MessageId>,
+        filename: &str,
+        content: Vec<u8>,
+    ) -> Result<File, SlackClientError> {
+        info!(
+            "Uploading file {} ({} bytes) to channel {}, thread {:?}",
+            filename,
+            content.len(),
+            channel,
+            thread
+        );
+        let upload = self
+            .call(files::GetUploadUrlExternalRequest {
+                filename,
+                length: content.len(),
+            })
+            .await?;
+
         let response = self
             .http
-            .post("https://slack.com/api/apps.connections.open")
-            .header(
-                "Authorization",
-                format!("Bearer {}", self.app_token.expose_secret()),
-            )
+            .post(upload.upload_url)
             .header("User-Agent", "slackbot-client")
-            .header("Accept", "application/json")
-            .header("Content-type", "application/x-www-form-urlencoded")
+            .header("Content-Type", "application/octet-stream")
+            .body(content)
             .send()
-            .await?
-            .json::<WebsocketUrlMessage>()
             .await?;
+        if !response.status().is_success() {
+            return Err(SlackClientError::Transport(format!(
+                "Failed to upload file contents, got status {}",
+                response.status()
+            )));
+        }
+
+        let response = self
+            .call(files::CompleteUploadExternalRequest {
+                files: vec![files::UploadedFile {
+                    id: &upload.file_id,
+                    title: filename,
+                }],
+                channel_id: channel,
+                thread_ts: thread.as_ref(),
+            })
+            .await?;
+
+        response.files.into_iter().next().ok_or_else(|| {
+            SlackClientError::Deserialisation(
+                "Slack did not return the file it uploaded".to_string(),
+            )
+        })
+    }
+
+    #[tracing::instrument]
+    async fn get_websocket_url(&self) -> Result<Url, SlackClientError> {
+        info!("Connecting to socket mode");
+        let response = self.call(apps::ConnectionsOpenRequest).await?;
 
         Url::parse(response.url.as_str()).map_err(SlackClientError::from)
     }
 }
+
+/// Reads the JSON body of a Web API response, unless Slack has rate limited us
+#[async_trait]
+trait SlackResponse {
+    async fn slack_json<T: DeserializeOwned>(self) -> Result<T, SlackClientError>;
+}
+
+#[async_trait]
+impl SlackResponse for reqwest::Response {
+    async fn slack_json<T: DeserializeOwned>(self) -> Result<T, SlackClientError> {
+        if self.status() == StatusCode::TOO_MANY_REQUESTS {
+            return Err(SlackClientError::rate_limited(self.headers()));
+        }
+        self.json::<T>().await.map_err(SlackClientError::from)
+    }
+}
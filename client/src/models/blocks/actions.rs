@@ -0,0 +1,13 @@
+use crate::models::blocks::elements::interactive::InteractiveElement;
+use builder_pattern::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// A row of interactive elements
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct ActionsBlock {
+    pub elements: Vec<InteractiveElement>,
+    #[default(None)]
+    pub block_id: Option<String>,
+}
@@ -0,0 +1,38 @@
+use crate::models::blocks::elements::image::ImageElement;
+use crate::models::blocks::objects::text::TextBody;
+use crate::models::blocks::Block;
+use builder_pattern::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// Small, muted text and images, e.g. who posted something and when
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct ContextBlock {
+    pub elements: Vec<ContextElement>,
+    #[default(None)]
+    pub block_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(tag = "type")]
+pub enum ContextElement {
+    #[serde(rename = "image")]
+    Image(ImageElement),
+    #[serde(rename = "plain_text")]
+    PlainText(TextBody),
+    #[serde(rename = "mrkdwn")]
+    Markdown(TextBody),
+}
+
+impl ContextBlock {
+    pub fn new_markdown(markdown: &str) -> Block {
+        Block::Context(ContextBlock {
+            elements: vec![ContextElement::Markdown(TextBody {
+                text: markdown.to_string(),
+                style: None,
+            })],
+            block_id: None,
+        })
+    }
+}
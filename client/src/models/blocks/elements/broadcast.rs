@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// `@here`, `@channel` or `@everyone`
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct BroadcastElement {
+    pub range: BroadcastRange,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum BroadcastRange {
+    Here,
+    Channel,
+    Everyone,
+}
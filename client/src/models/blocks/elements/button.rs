@@ -0,0 +1,35 @@
+use crate::models::blocks::objects::confirm::ConfirmationDialog;
+use crate::models::blocks::objects::text::Text;
+use builder_pattern::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct ButtonElement {
+    pub text: Text,
+    #[default(None)]
+    pub action_id: Option<String>,
+    /// Opens the URL in the user's browser, the app still receives an interaction payload
+    #[default(None)]
+    pub url: Option<String>,
+    /// Sent to the app when the button is clicked
+    #[default(None)]
+    pub value: Option<String>,
+    #[default(None)]
+    pub style: Option<ButtonStyle>,
+    #[default(None)]
+    pub confirm: Option<ConfirmationDialog>,
+    #[default(None)]
+    pub accessibility_label: Option<String>,
+}
+
+/// Buttons without a style are plain
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum ButtonStyle {
+    /// Green, for the action a user is most likely to take
+    Primary,
+    /// Red, for destructive actions
+    Danger,
+}
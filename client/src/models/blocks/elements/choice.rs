@@ -0,0 +1,35 @@
+use crate::models::blocks::objects::confirm::ConfirmationDialog;
+use crate::models::blocks::objects::option::OptionObject;
+use builder_pattern::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// Choose any number of up to ten options
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct CheckboxesElement {
+    pub options: Vec<OptionObject>,
+    #[default(None)]
+    pub action_id: Option<String>,
+    #[default(None)]
+    pub initial_options: Option<Vec<OptionObject>>,
+    #[default(None)]
+    pub confirm: Option<ConfirmationDialog>,
+    #[default(None)]
+    pub focus_on_load: Option<bool>,
+}
+
+/// Choose exactly one of up to ten options
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct RadioButtonsElement {
+    pub options: Vec<OptionObject>,
+    #[default(None)]
+    pub action_id: Option<String>,
+    #[default(None)]
+    pub initial_option: Option<OptionObject>,
+    #[default(None)]
+    pub confirm: Option<ConfirmationDialog>,
+    #[default(None)]
+    pub focus_on_load: Option<bool>,
+}
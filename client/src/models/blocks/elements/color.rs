@@ -0,0 +1,8 @@
+use builder_pattern::Builder;
+use serde::{Deserialize, Serialize};
+
+/// A colour swatch, Slack adds these when users post hex codes such as `#F405B3`
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct ColorElement {
+    pub value: String,
+}
@@ -0,0 +1,18 @@
+use builder_pattern::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// A date shown in each reader's own time zone
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct DateElement {
+    /// Seconds since the Unix epoch
+    pub timestamp: i64,
+    /// A template such as `{date_short} at {time}`, see Slack's date formatting docs
+    pub format: String,
+    #[default(None)]
+    pub url: Option<String>,
+    /// Shown by clients that can't format the date
+    #[default(None)]
+    pub fallback: Option<String>,
+}
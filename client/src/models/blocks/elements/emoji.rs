@@ -1,7 +1,12 @@
 use builder_pattern::Builder;
 use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
+#[skip_serializing_none]
 #[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
 pub struct EmojiElement {
     pub name: String,
+    /// The emoji's code points in hex, separated by `-`, e.g. `1f44d-1f3fb`. Custom emoji have none.
+    #[default(None)]
+    pub unicode: Option<String>,
 }
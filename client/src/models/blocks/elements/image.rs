@@ -0,0 +1,9 @@
+use builder_pattern::Builder;
+use serde::{Deserialize, Serialize};
+
+/// A small image, for context blocks and section accessories
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct ImageElement {
+    pub image_url: String,
+    pub alt_text: String,
+}
@@ -0,0 +1,84 @@
+use crate::models::blocks::elements::button::ButtonElement;
+use crate::models::blocks::elements::choice::{CheckboxesElement, RadioButtonsElement};
+use crate::models::blocks::elements::image::ImageElement;
+use crate::models::blocks::elements::overflow::OverflowElement;
+use crate::models::blocks::elements::picker::{DatePickerElement, TimePickerElement};
+use crate::models::blocks::elements::select::{
+    ChannelsSelectElement, ConversationsSelectElement, ExternalSelectElement,
+    MultiChannelsSelectElement, MultiConversationsSelectElement, MultiExternalSelectElement,
+    MultiStaticSelectElement, MultiUsersSelectElement, StaticSelectElement, UsersSelectElement,
+};
+use crate::models::blocks::objects::text::Text;
+use builder_pattern::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// Elements for section accessories, actions blocks and input blocks
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum InteractiveElement {
+    Image(ImageElement),
+    PlainTextInput(PlainTextInputElement),
+    Button(ButtonElement),
+    StaticSelect(StaticSelectElement),
+    ExternalSelect(ExternalSelectElement),
+    UsersSelect(UsersSelectElement),
+    ConversationsSelect(ConversationsSelectElement),
+    ChannelsSelect(ChannelsSelectElement),
+    MultiStaticSelect(MultiStaticSelectElement),
+    MultiExternalSelect(MultiExternalSelectElement),
+    MultiUsersSelect(MultiUsersSelectElement),
+    MultiConversationsSelect(MultiConversationsSelectElement),
+    MultiChannelsSelect(MultiChannelsSelectElement),
+    #[serde(rename = "datepicker")]
+    DatePicker(DatePickerElement),
+    #[serde(rename = "timepicker")]
+    TimePicker(TimePickerElement),
+    Overflow(OverflowElement),
+    Checkboxes(CheckboxesElement),
+    RadioButtons(RadioButtonsElement),
+}
+
+impl InteractiveElement {
+    /// Identifies the element in interaction payloads, images have no action
+    pub fn action_id(&self) -> Option<&str> {
+        match self {
+            InteractiveElement::Image(_) => None,
+            InteractiveElement::PlainTextInput(element) => element.action_id.as_deref(),
+            InteractiveElement::Button(element) => element.action_id.as_deref(),
+            InteractiveElement::StaticSelect(element) => element.action_id.as_deref(),
+            InteractiveElement::ExternalSelect(element) => element.action_id.as_deref(),
+            InteractiveElement::UsersSelect(element) => element.action_id.as_deref(),
+            InteractiveElement::ConversationsSelect(element) => element.action_id.as_deref(),
+            InteractiveElement::ChannelsSelect(element) => element.action_id.as_deref(),
+            InteractiveElement::MultiStaticSelect(element) => element.action_id.as_deref(),
+            InteractiveElement::MultiExternalSelect(element) => element.action_id.as_deref(),
+            InteractiveElement::MultiUsersSelect(element) => element.action_id.as_deref(),
+            InteractiveElement::MultiConversationsSelect(element) => element.action_id.as_deref(),
+            InteractiveElement::MultiChannelsSelect(element) => element.action_id.as_deref(),
+            InteractiveElement::DatePicker(element) => element.action_id.as_deref(),
+            InteractiveElement::TimePicker(element) => element.action_id.as_deref(),
+            InteractiveElement::Overflow(element) => element.action_id.as_deref(),
+            InteractiveElement::Checkboxes(element) => element.action_id.as_deref(),
+            InteractiveElement::RadioButtons(element) => element.action_id.as_deref(),
+        }
+    }
+}
+
+/// A free text field, for input blocks
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct PlainTextInputElement {
+    #[default(None)]
+    pub action_id: Option<String>,
+    #[default(None)]
+    pub placeholder: Option<Text>,
+    #[default(None)]
+    pub initial_value: Option<String>,
+    #[default(None)]
+    pub multiline: Option<bool>,
+    #[default(None)]
+    pub min_length: Option<u32>,
+    #[default(None)]
+    pub max_length: Option<u32>,
+}
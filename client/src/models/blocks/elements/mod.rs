@@ -1,20 +1,74 @@
+use crate::models::blocks::elements::broadcast::BroadcastElement;
+use crate::models::blocks::elements::channel::ChannelElement;
+use crate::models::blocks::elements::color::ColorElement;
+use crate::models::blocks::elements::date::DateElement;
 use crate::models::blocks::elements::emoji::EmojiElement;
-use crate::models::blocks::elements::text::LinkElement;
+use crate::models::blocks::elements::text::{
+    LinkElement, RichTextListElement, RichTextPreformattedElement, RichTextQuoteElement,
+};
 use crate::models::blocks::elements::user::UserElement;
+use crate::models::blocks::elements::usergroup::UsergroupElement;
 use crate::models::blocks::objects::text::TextBody;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use text::RichTextSectionElement;
 
+pub mod broadcast;
+pub mod button;
+pub mod channel;
+pub mod choice;
+pub mod color;
+pub mod date;
 pub mod emoji;
+pub mod image;
+pub mod interactive;
+pub mod overflow;
+pub mod picker;
+pub mod select;
 pub mod text;
 pub mod user;
+pub mod usergroup;
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
-#[serde(tag = "type", rename_all = "snake_case")]
+#[serde(remote = "Self", tag = "type", rename_all = "snake_case")]
 pub enum BlockElement {
     RichTextSection(RichTextSectionElement),
+    RichTextList(RichTextListElement),
+    RichTextQuote(RichTextQuoteElement),
+    RichTextPreformatted(RichTextPreformattedElement),
     Text(TextBody),
     Link(LinkElement),
     Emoji(EmojiElement),
     User(UserElement),
+    Channel(ChannelElement),
+    Usergroup(UsergroupElement),
+    Broadcast(BroadcastElement),
+    Date(DateElement),
+    Color(ColorElement),
+    /// An element we don't model, kept as Slack sent it so the rest of the block still parses
+    #[serde(skip)]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
+}
+
+impl<'de> Deserialize<'de> for BlockElement {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = serde_json::Value::deserialize(deserializer)?;
+        Ok(
+            BlockElement::deserialize(&raw).unwrap_or_else(|_| BlockElement::Unknown {
+                type_name: raw["type"].as_str().unwrap_or_default().to_string(),
+                raw,
+            }),
+        )
+    }
+}
+
+impl Serialize for BlockElement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            BlockElement::Unknown { raw, .. } => raw.serialize(serializer),
+            element => BlockElement::serialize(element, serializer),
+        }
+    }
 }
@@ -0,0 +1,16 @@
+use crate::models::blocks::objects::confirm::ConfirmationDialog;
+use crate::models::blocks::objects::option::OptionObject;
+use builder_pattern::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// A "..." button that opens a menu of up to five options
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct OverflowElement {
+    pub options: Vec<OptionObject>,
+    #[default(None)]
+    pub action_id: Option<String>,
+    #[default(None)]
+    pub confirm: Option<ConfirmationDialog>,
+}
@@ -0,0 +1,40 @@
+use crate::models::blocks::objects::confirm::ConfirmationDialog;
+use crate::models::blocks::objects::text::Text;
+use builder_pattern::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct DatePickerElement {
+    #[default(None)]
+    pub action_id: Option<String>,
+    /// In the format `YYYY-MM-DD`
+    #[default(None)]
+    pub initial_date: Option<String>,
+    #[default(None)]
+    pub placeholder: Option<Text>,
+    #[default(None)]
+    pub confirm: Option<ConfirmationDialog>,
+    #[default(None)]
+    pub focus_on_load: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct TimePickerElement {
+    #[default(None)]
+    pub action_id: Option<String>,
+    /// In the 24 hour format `HH:mm`
+    #[default(None)]
+    pub initial_time: Option<String>,
+    #[default(None)]
+    pub placeholder: Option<Text>,
+    /// An IANA time zone, e.g. `Europe/London`. Defaults to the user's time zone.
+    #[default(None)]
+    pub timezone: Option<String>,
+    #[default(None)]
+    pub confirm: Option<ConfirmationDialog>,
+    #[default(None)]
+    pub focus_on_load: Option<bool>,
+}
@@ -0,0 +1,208 @@
+//! Select menus, letting users pick one item, and multi-select menus for picking several
+
+use crate::models::blocks::objects::confirm::ConfirmationDialog;
+use crate::models::blocks::objects::option::{OptionGroup, OptionObject};
+use crate::models::blocks::objects::text::Text;
+use builder_pattern::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// Choose from options defined in the message, set either `options` or `option_groups`
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct StaticSelectElement {
+    #[default(None)]
+    pub action_id: Option<String>,
+    #[default(None)]
+    pub placeholder: Option<Text>,
+    #[default(None)]
+    pub options: Option<Vec<OptionObject>>,
+    #[default(None)]
+    pub option_groups: Option<Vec<OptionGroup>>,
+    #[default(None)]
+    pub initial_option: Option<OptionObject>,
+    #[default(None)]
+    pub confirm: Option<ConfirmationDialog>,
+    #[default(None)]
+    pub focus_on_load: Option<bool>,
+}
+
+/// Choose from options the app serves from its options load URL
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct ExternalSelectElement {
+    #[default(None)]
+    pub action_id: Option<String>,
+    #[default(None)]
+    pub placeholder: Option<Text>,
+    #[default(None)]
+    pub initial_option: Option<OptionObject>,
+    /// How many characters the user must type before options are requested
+    #[default(None)]
+    pub min_query_length: Option<u32>,
+    #[default(None)]
+    pub confirm: Option<ConfirmationDialog>,
+    #[default(None)]
+    pub focus_on_load: Option<bool>,
+}
+
+/// Choose a user in the workspace
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct UsersSelectElement {
+    #[default(None)]
+    pub action_id: Option<String>,
+    #[default(None)]
+    pub placeholder: Option<Text>,
+    #[default(None)]
+    pub initial_user: Option<String>,
+    #[default(None)]
+    pub confirm: Option<ConfirmationDialog>,
+    #[default(None)]
+    pub focus_on_load: Option<bool>,
+}
+
+/// Choose a public channel, private channel, DM or group DM
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct ConversationsSelectElement {
+    #[default(None)]
+    pub action_id: Option<String>,
+    #[default(None)]
+    pub placeholder: Option<Text>,
+    #[default(None)]
+    pub initial_conversation: Option<String>,
+    /// Start with the conversation the user is in selected, overrides `initial_conversation`
+    #[default(None)]
+    pub default_to_current_conversation: Option<bool>,
+    #[default(None)]
+    pub filter: Option<ConversationFilter>,
+    #[default(None)]
+    pub confirm: Option<ConfirmationDialog>,
+    #[default(None)]
+    pub focus_on_load: Option<bool>,
+}
+
+/// Choose a public channel
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct ChannelsSelectElement {
+    #[default(None)]
+    pub action_id: Option<String>,
+    #[default(None)]
+    pub placeholder: Option<Text>,
+    #[default(None)]
+    pub initial_channel: Option<String>,
+    #[default(None)]
+    pub confirm: Option<ConfirmationDialog>,
+    #[default(None)]
+    pub focus_on_load: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct MultiStaticSelectElement {
+    #[default(None)]
+    pub action_id: Option<String>,
+    #[default(None)]
+    pub placeholder: Option<Text>,
+    #[default(None)]
+    pub options: Option<Vec<OptionObject>>,
+    #[default(None)]
+    pub option_groups: Option<Vec<OptionGroup>>,
+    #[default(None)]
+    pub initial_options: Option<Vec<OptionObject>>,
+    #[default(None)]
+    pub max_selected_items: Option<u32>,
+    #[default(None)]
+    pub confirm: Option<ConfirmationDialog>,
+    #[default(None)]
+    pub focus_on_load: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct MultiExternalSelectElement {
+    #[default(None)]
+    pub action_id: Option<String>,
+    #[default(None)]
+    pub placeholder: Option<Text>,
+    #[default(None)]
+    pub initial_options: Option<Vec<OptionObject>>,
+    #[default(None)]
+    pub min_query_length: Option<u32>,
+    #[default(None)]
+    pub max_selected_items: Option<u32>,
+    #[default(None)]
+    pub confirm: Option<ConfirmationDialog>,
+    #[default(None)]
+    pub focus_on_load: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct MultiUsersSelectElement {
+    #[default(None)]
+    pub action_id: Option<String>,
+    #[default(None)]
+    pub placeholder: Option<Text>,
+    #[default(None)]
+    pub initial_users: Option<Vec<String>>,
+    #[default(None)]
+    pub max_selected_items: Option<u32>,
+    #[default(None)]
+    pub confirm: Option<ConfirmationDialog>,
+    #[default(None)]
+    pub focus_on_load: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct MultiConversationsSelectElement {
+    #[default(None)]
+    pub action_id: Option<String>,
+    #[default(None)]
+    pub placeholder: Option<Text>,
+    #[default(None)]
+    pub initial_conversations: Option<Vec<String>>,
+    #[default(None)]
+    pub default_to_current_conversation: Option<bool>,
+    #[default(None)]
+    pub filter: Option<ConversationFilter>,
+    #[default(None)]
+    pub max_selected_items: Option<u32>,
+    #[default(None)]
+    pub confirm: Option<ConfirmationDialog>,
+    #[default(None)]
+    pub focus_on_load: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct MultiChannelsSelectElement {
+    #[default(None)]
+    pub action_id: Option<String>,
+    #[default(None)]
+    pub placeholder: Option<Text>,
+    #[default(None)]
+    pub initial_channels: Option<Vec<String>>,
+    #[default(None)]
+    pub max_selected_items: Option<u32>,
+    #[default(None)]
+    pub confirm: Option<ConfirmationDialog>,
+    #[default(None)]
+    pub focus_on_load: Option<bool>,
+}
+
+/// Limits which conversations a conversations select menu offers
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct ConversationFilter {
+    /// Any of `im`, `mpim`, `private` and `public`
+    #[default(None)]
+    pub include: Option<Vec<String>>,
+    #[default(None)]
+    pub exclude_external_shared_channels: Option<bool>,
+    #[default(None)]
+    pub exclude_bot_users: Option<bool>,
+}
@@ -1,13 +1,61 @@
 use crate::models::blocks::elements::BlockElement;
+use crate::models::blocks::objects::text::TextStyle;
 use builder_pattern::Builder;
 use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
 #[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
 pub struct RichTextSectionElement {
     pub elements: Vec<BlockElement>,
 }
 
+/// A bulleted or numbered list, each item is a [`RichTextSectionElement`]
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct RichTextListElement {
+    pub style: ListStyle,
+    pub elements: Vec<BlockElement>,
+    /// How many levels the list is nested, Slack sends nested lists as separate elements
+    #[default(None)]
+    pub indent: Option<u32>,
+    /// The number an ordered list continues from
+    #[default(None)]
+    pub offset: Option<u32>,
+    #[default(None)]
+    pub border: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum ListStyle {
+    Bullet,
+    Ordered,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct RichTextQuoteElement {
+    pub elements: Vec<BlockElement>,
+    #[default(None)]
+    pub border: Option<u32>,
+}
+
+/// A code block
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct RichTextPreformattedElement {
+    pub elements: Vec<BlockElement>,
+    #[default(None)]
+    pub border: Option<u32>,
+}
+
+#[skip_serializing_none]
 #[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
 pub struct LinkElement {
     pub url: String,
+    /// Shown instead of the URL
+    #[default(None)]
+    pub text: Option<String>,
+    #[default(None)]
+    pub style: Option<TextStyle>,
 }
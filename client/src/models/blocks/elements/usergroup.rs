@@ -0,0 +1,13 @@
+use crate::models::blocks::objects::text::TextStyle;
+use builder_pattern::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct UsergroupElement {
+    #[serde(rename = "usergroup_id")]
+    pub id: String,
+    #[default(None)]
+    pub style: Option<TextStyle>,
+}
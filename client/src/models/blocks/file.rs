@@ -0,0 +1,15 @@
+use builder_pattern::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// A remote file, Slack adds these to messages when remote files are shared
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct FileBlock {
+    pub external_id: String,
+    /// Always `remote`
+    #[default(String::from("remote"))]
+    pub source: String,
+    #[default(None)]
+    pub block_id: Option<String>,
+}
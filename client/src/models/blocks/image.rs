@@ -0,0 +1,15 @@
+use crate::models::blocks::objects::text::Text;
+use builder_pattern::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct ImageBlock {
+    pub image_url: String,
+    pub alt_text: String,
+    #[default(None)]
+    pub title: Option<Text>,
+    #[default(None)]
+    pub block_id: Option<String>,
+}
@@ -0,0 +1,22 @@
+use crate::models::blocks::elements::interactive::InteractiveElement;
+use crate::models::blocks::objects::text::Text;
+use builder_pattern::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// Collects information from users, in modals and messages
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct InputBlock {
+    pub label: Text,
+    pub element: InteractiveElement,
+    /// Send an interaction payload as soon as the element changes, rather than on submission
+    #[default(None)]
+    pub dispatch_action: Option<bool>,
+    #[default(None)]
+    pub hint: Option<Text>,
+    #[default(None)]
+    pub optional: Option<bool>,
+    #[default(None)]
+    pub block_id: Option<String>,
+}
@@ -0,0 +1,84 @@
+/// Describe a message's blocks in a few lines, producing a `Vec<Block>`.
+///
+/// Each block is one of:
+///
+/// * `header(...)`, a header in plain text
+/// * `markdown(...)`, a section of `mrkdwn` text
+/// * `text(...)`, a section of plain text
+/// * `context(...)`, small `mrkdwn` text
+/// * `rich_text(...)`, a rich text block with a single section of text
+/// * `image(url, alt_text)`
+/// * `divider`
+/// * `block(expr)`, any other [`Block`](crate::models::blocks::Block)
+///
+/// Text takes the same arguments as `format!`, so values can be interpolated:
+///
+/// ```
+/// use client::blocks;
+/// use client::models::blocks::Block;
+///
+/// let name = "ferris";
+/// let blocks: Vec<Block> = blocks! {
+///     header("Emoji changelog"),
+///     markdown(":heavy_plus_sign: :{name}: `:{}:`", name),
+///     divider,
+///     context("Added by <@{}>", "U123"),
+/// };
+///
+/// assert_eq!(blocks.len(), 4);
+/// ```
+#[macro_export]
+macro_rules! blocks {
+    (@block divider) => {
+        $crate::models::blocks::Block::Divider
+    };
+    (@block header($($format:tt)+)) => {
+        $crate::models::blocks::text::HeaderBlock::new(&format!($($format)+))
+    };
+    (@block markdown($($format:tt)+)) => {
+        $crate::models::blocks::section::SectionBlock::new_markdown(&format!($($format)+))
+    };
+    (@block text($($format:tt)+)) => {
+        $crate::models::blocks::Block::Section(
+            $crate::models::blocks::section::SectionBlock::new()
+                .text(Some($crate::models::blocks::objects::text::Text::PlainText(
+                    $crate::models::blocks::objects::text::TextBody::new()
+                        .text(format!($($format)+))
+                        .build(),
+                )))
+                .build(),
+        )
+    };
+    (@block context($($format:tt)+)) => {
+        $crate::models::blocks::context::ContextBlock::new_markdown(&format!($($format)+))
+    };
+    (@block rich_text($($format:tt)+)) => {
+        $crate::models::blocks::Block::RichText(
+            $crate::models::blocks::text::RichTextBlock::new()
+                .elements(vec![$crate::models::blocks::elements::BlockElement::RichTextSection(
+                    $crate::models::blocks::elements::text::RichTextSectionElement::new()
+                        .elements(vec![$crate::models::blocks::elements::BlockElement::Text(
+                            $crate::models::blocks::objects::text::TextBody::new()
+                                .text(format!($($format)+))
+                                .build(),
+                        )])
+                        .build(),
+                )])
+                .build(),
+        )
+    };
+    (@block image($url:expr, $alt_text:expr $(,)?)) => {
+        $crate::models::blocks::Block::Image(
+            $crate::models::blocks::image::ImageBlock::new()
+                .image_url($url.to_string())
+                .alt_text($alt_text.to_string())
+                .build(),
+        )
+    };
+    (@block block($block:expr $(,)?)) => {
+        $block
+    };
+    ($($kind:ident $(($($args:tt)*))?),* $(,)?) => {
+        vec![$($crate::blocks!(@block $kind $(($($args)*))?)),*]
+    };
+}
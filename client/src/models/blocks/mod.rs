@@ -1,36 +1,129 @@
+pub mod actions;
+pub mod context;
 pub mod elements;
+pub mod file;
+pub mod image;
+pub mod input;
+mod macros;
 pub mod objects;
+pub mod render;
 pub mod section;
 pub mod text;
+pub mod validation;
+pub mod video;
 
+use crate::models::blocks::actions::ActionsBlock;
+use crate::models::blocks::context::ContextBlock;
+use crate::models::blocks::file::FileBlock;
+use crate::models::blocks::image::ImageBlock;
+use crate::models::blocks::input::InputBlock;
 use crate::models::blocks::section::SectionBlock;
 use crate::models::blocks::text::{HeaderBlock, RichTextBlock};
-use serde::{Deserialize, Serialize};
+use crate::models::blocks::video::VideoBlock;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
-#[serde(tag = "type", rename_all = "snake_case")]
+#[serde(remote = "Self", tag = "type", rename_all = "snake_case")]
 pub enum Block {
     RichText(RichTextBlock),
     Divider,
     Section(SectionBlock),
     Header(HeaderBlock),
+    Context(ContextBlock),
+    Image(ImageBlock),
+    Actions(ActionsBlock),
+    Input(InputBlock),
+    File(FileBlock),
+    Video(VideoBlock),
+    /// A block we don't model, kept as Slack sent it so the rest of the message still parses
+    #[serde(skip)]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
+}
+
+impl<'de> Deserialize<'de> for Block {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = serde_json::Value::deserialize(deserializer)?;
+        Ok(Block::deserialize(&raw).unwrap_or_else(|_| Block::Unknown {
+            type_name: raw["type"].as_str().unwrap_or_default().to_string(),
+            raw,
+        }))
+    }
+}
+
+impl Serialize for Block {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Block::Unknown { raw, .. } => raw.serialize(serializer),
+            block => Block::serialize(block, serializer),
+        }
+    }
+}
+
+impl Block {
+    pub fn block_id(&self) -> Option<&str> {
+        match self {
+            Block::RichText(block) => block.block_id.as_deref(),
+            Block::Section(block) => block.block_id.as_deref(),
+            Block::Context(block) => block.block_id.as_deref(),
+            Block::Image(block) => block.block_id.as_deref(),
+            Block::Actions(block) => block.block_id.as_deref(),
+            Block::Input(block) => block.block_id.as_deref(),
+            Block::File(block) => block.block_id.as_deref(),
+            Block::Video(block) => block.block_id.as_deref(),
+            Block::Unknown { raw, .. } => raw["block_id"].as_str(),
+            Block::Divider | Block::Header(_) => None,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use crate::models::blocks::context::ContextElement;
+    use crate::models::blocks::elements::broadcast::{BroadcastElement, BroadcastRange};
+    use crate::models::blocks::elements::button::{ButtonElement, ButtonStyle};
+    use crate::models::blocks::elements::channel::ChannelElement;
+    use crate::models::blocks::elements::choice::RadioButtonsElement;
     use crate::models::blocks::elements::emoji::EmojiElement;
-    use crate::models::blocks::elements::text::RichTextSectionElement;
+    use crate::models::blocks::elements::image::ImageElement;
+    use crate::models::blocks::elements::interactive::{InteractiveElement, PlainTextInputElement};
+    use crate::models::blocks::elements::text::{
+        LinkElement, ListStyle, RichTextListElement, RichTextSectionElement,
+    };
     use crate::models::blocks::elements::user::UserElement;
     use crate::models::blocks::elements::BlockElement;
-    use crate::models::blocks::objects::text::TextBody;
+    use crate::models::blocks::objects::confirm::ConfirmationDialog;
+    use crate::models::blocks::objects::option::OptionObject;
+    use crate::models::blocks::objects::text::{Text, TextBody, TextStyle};
     use crate::models::blocks::Block;
+    use serde_json::{json, Value};
 
     const EMOJI_CHANGELOG_MESSAGE: &str = "[ { \"type\": \"header\", \"text\": { \"type\": \"plain_text\", \"text\": \"Emoji changelog\" } }, { \"type\": \"section\", \"text\": { \"type\": \"mrkdwn\", \"text\": \":heavy_plus_sign: :smile: `:smile:`\\n        By <@userid>\" } } ] ";
     const RICH_TEXT_BLOCK_WITH_EMOJI: &str = "[{\"type\":\"rich_text\",\"block_id\":\"+QITd\",\"elements\":[{\"type\":\"rich_text_section\",\"elements\":[{\"type\":\"emoji\",\"name\":\"mild-panic\"},{\"type\":\"text\",\"text\":\"++\"}]}]}]";
     const RICH_TEXT_BLOCK_WITH_USER_LINK: &str = "[{\"type\": \"rich_text\",\"block_id\": \"6aHD1\",\"elements\": [{\"type\": \"rich_text_section\",\"elements\": [{\"type\": \"text\",\"text\": \"Woohay! Nice one \"},{\"type\": \"user\",\"user_id\": \"U2M31DUM6\"},{\"type\": \"text\",\"text\": \" sam++\"}]}]}]";
 
+    const CONTEXT_BLOCK: &str = "[{\"type\":\"context\",\"block_id\":\"ctx1\",\"elements\":[{\"type\":\"image\",\"image_url\":\"https://example.com/pin.png\",\"alt_text\":\"pin\"},{\"type\":\"mrkdwn\",\"text\":\"Location: *Dogpatch*\"}]}]";
+    const IMAGE_BLOCK: &str = "[{\"type\":\"image\",\"title\":{\"type\":\"plain_text\",\"text\":\"A kitten\"},\"block_id\":\"image4\",\"image_url\":\"https://example.com/kitten.png\",\"alt_text\":\"An incredibly cute kitten.\"}]";
+    const SECTION_WITH_ACCESSORY: &str = "[{\"type\":\"section\",\"block_id\":\"section567\",\"text\":{\"type\":\"mrkdwn\",\"text\":\"*Sally* has requested you set the deadline\"},\"accessory\":{\"type\":\"image\",\"image_url\":\"https://example.com/sally.png\",\"alt_text\":\"Sally\"}}]";
+    const INPUT_BLOCK: &str = "[{\"type\":\"input\",\"block_id\":\"feedback\",\"label\":{\"type\":\"plain_text\",\"text\":\"Feedback\"},\"element\":{\"type\":\"plain_text_input\",\"action_id\":\"feedback_text\",\"multiline\":true},\"optional\":true}]";
+    const FILE_AND_VIDEO_BLOCKS: &str = "[{\"type\":\"file\",\"external_id\":\"ABCD1\",\"source\":\"remote\",\"block_id\":\"file1\"},{\"type\":\"video\",\"title\":{\"type\":\"plain_text\",\"text\":\"Use the Events API\"},\"title_url\":\"https://www.youtube.com/watch?v=8876OZV_Yy0\",\"video_url\":\"https://www.youtube.com/embed/8876OZV_Yy0\",\"thumbnail_url\":\"https://i.ytimg.com/vi/8876OZV_Yy0/hqdefault.jpg\",\"alt_text\":\"How to use Slack\",\"author_name\":\"Arcade Lab\",\"provider_name\":\"YouTube\"}]";
+    const APPROVAL_ACTIONS_BLOCK: &str = "[{\"type\":\"actions\",\"block_id\":\"approval\",\"elements\":[{\"type\":\"button\",\"text\":{\"type\":\"plain_text\",\"text\":\"Approve\"},\"style\":\"primary\",\"value\":\"approve\",\"action_id\":\"approve\",\"confirm\":{\"title\":{\"type\":\"plain_text\",\"text\":\"Are you sure?\"},\"text\":{\"type\":\"mrkdwn\",\"text\":\"This can't be undone\"},\"confirm\":{\"type\":\"plain_text\",\"text\":\"Do it\"},\"deny\":{\"type\":\"plain_text\",\"text\":\"Stop\"}}},{\"type\":\"button\",\"text\":{\"type\":\"plain_text\",\"text\":\"Deny\"},\"style\":\"danger\",\"value\":\"deny\"},{\"type\":\"datepicker\",\"action_id\":\"due\",\"initial_date\":\"1990-04-28\",\"placeholder\":{\"type\":\"plain_text\",\"text\":\"Select a date\"}},{\"type\":\"overflow\",\"options\":[{\"text\":{\"type\":\"plain_text\",\"text\":\"Docs\"},\"value\":\"docs\",\"url\":\"https://api.slack.com\"}]}]}]";
+    const SELECT_MENU_BLOCKS: &str = "[{\"type\":\"section\",\"text\":{\"type\":\"mrkdwn\",\"text\":\"Pick a snack\"},\"accessory\":{\"type\":\"static_select\",\"action_id\":\"snack\",\"option_groups\":[{\"label\":{\"type\":\"plain_text\",\"text\":\"Fruit\"},\"options\":[{\"text\":{\"type\":\"plain_text\",\"text\":\"Apple\"},\"value\":\"apple\"}]}]}},{\"type\":\"input\",\"label\":{\"type\":\"plain_text\",\"text\":\"Reviewers\"},\"element\":{\"type\":\"multi_users_select\",\"initial_users\":[\"U123\"],\"max_selected_items\":3}},{\"type\":\"actions\",\"elements\":[{\"type\":\"conversations_select\",\"default_to_current_conversation\":true,\"filter\":{\"include\":[\"public\",\"private\"],\"exclude_bot_users\":true}},{\"type\":\"channels_select\",\"initial_channel\":\"C123\"},{\"type\":\"external_select\",\"min_query_length\":3},{\"type\":\"multi_static_select\",\"options\":[{\"text\":{\"type\":\"plain_text\",\"text\":\"Tea\"},\"value\":\"tea\"}]},{\"type\":\"multi_external_select\"},{\"type\":\"multi_conversations_select\",\"initial_conversations\":[\"D123\"]},{\"type\":\"multi_channels_select\",\"initial_channels\":[\"C123\"]},{\"type\":\"users_select\",\"initial_user\":\"U123\"},{\"type\":\"timepicker\",\"initial_time\":\"13:37\",\"timezone\":\"Europe/London\"},{\"type\":\"checkboxes\",\"options\":[{\"text\":{\"type\":\"mrkdwn\",\"text\":\"*Tabs*\"},\"description\":{\"type\":\"plain_text\",\"text\":\"Not spaces\"},\"value\":\"tabs\"}]},{\"type\":\"radio_buttons\",\"options\":[{\"text\":{\"type\":\"plain_text\",\"text\":\"Yes\"},\"value\":\"yes\"}]}]}]";
+    const FORMATTED_RICH_TEXT: &str = "[{\"type\":\"rich_text\",\"block_id\":\"fmt1\",\"elements\":[{\"type\":\"rich_text_section\",\"elements\":[{\"type\":\"broadcast\",\"range\":\"here\"},{\"type\":\"text\",\"text\":\" the \"},{\"type\":\"text\",\"text\":\"release\",\"style\":{\"bold\":true,\"italic\":true}},{\"type\":\"text\",\"text\":\" is out in \"},{\"type\":\"channel\",\"channel_id\":\"C0123\"},{\"type\":\"text\",\"text\":\", thanks \"},{\"type\":\"usergroup\",\"usergroup_id\":\"S0123\"},{\"type\":\"text\",\"text\":\" see \"},{\"type\":\"link\",\"url\":\"https://example.com/notes\",\"text\":\"the notes\"}]},{\"type\":\"rich_text_list\",\"style\":\"bullet\",\"indent\":0,\"border\":0,\"elements\":[{\"type\":\"rich_text_section\",\"elements\":[{\"type\":\"text\",\"text\":\"faster\"}]},{\"type\":\"rich_text_section\",\"elements\":[{\"type\":\"text\",\"text\":\"old\",\"style\":{\"strike\":true}}]}]},{\"type\":\"rich_text_quote\",\"elements\":[{\"type\":\"text\",\"text\":\"Ship it\"}]},{\"type\":\"rich_text_preformatted\",\"border\":0,\"elements\":[{\"type\":\"text\",\"text\":\"cargo run\"}]},{\"type\":\"rich_text_section\",\"elements\":[{\"type\":\"date\",\"timestamp\":1700000000,\"format\":\"{date_short} at {time}\",\"fallback\":\"Nov 14th\"},{\"type\":\"text\",\"text\":\" in \"},{\"type\":\"color\",\"value\":\"#F405B3\"},{\"type\":\"text\",\"text\":\"x\",\"style\":{\"code\":true}}]}]}]";
+
+    /// Parse `json`, checking it serialises back to the same JSON
+    fn parse_round_trip(json: &str) -> Vec<Block> {
+        let blocks: Vec<Block> = serde_json::from_str(json).unwrap();
+
+        let original: Value = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_value(&blocks).unwrap(), original);
+        blocks
+    }
+
     #[test]
     fn should_parse_emoji_changelog_message() {
         let result: Vec<Block> = serde_json::from_str(EMOJI_CHANGELOG_MESSAGE).unwrap();
@@ -56,13 +149,16 @@ mod tests {
                 elements: vec![BlockElement::RichTextSection(RichTextSectionElement {
                     elements: vec![
                         BlockElement::Emoji(EmojiElement {
-                            name: "mild-panic".to_string()
+                            name: "mild-panic".to_string(),
+                            unicode: None,
                         }),
                         BlockElement::Text(TextBody {
-                            text: "++".to_string()
+                            text: "++".to_string(),
+                            style: None,
                         })
                     ],
-                })]
+                })],
+                block_id: Some("+QITd".to_string()),
             })]
         )
     }
@@ -77,17 +173,380 @@ mod tests {
                 elements: vec![BlockElement::RichTextSection(RichTextSectionElement {
                     elements: vec![
                         BlockElement::Text(TextBody {
-                            text: "Woohay! Nice one ".to_string()
+                            text: "Woohay! Nice one ".to_string(),
+                            style: None,
                         }),
                         BlockElement::User(UserElement {
                             id: "U2M31DUM6".to_string()
                         }),
                         BlockElement::Text(TextBody {
-                            text: " sam++".to_string()
+                            text: " sam++".to_string(),
+                            style: None,
                         })
                     ]
-                })]
+                })],
+                block_id: Some("6aHD1".to_string()),
             })]
         )
     }
+
+    #[test]
+    fn should_round_trip_rich_text_block_id() {
+        let result = parse_round_trip(RICH_TEXT_BLOCK_WITH_EMOJI);
+
+        assert!(
+            matches!(&result[0], Block::RichText(block) if block.block_id.as_deref() == Some("+QITd"))
+        );
+    }
+
+    #[test]
+    fn should_round_trip_unknown_blocks_and_elements() {
+        let json = "[{\"type\":\"brand_new_block\",\"block_id\":\"new1\",\"stuff\":[1,2]},{\"type\":\"rich_text\",\"elements\":[{\"type\":\"rich_text_section\",\"elements\":[{\"type\":\"brand_new_element\",\"value\":\"?\"},{\"type\":\"text\",\"text\":\"still here\"}]}]}]";
+
+        let result = parse_round_trip(json);
+
+        assert!(
+            matches!(&result[0], Block::Unknown { type_name, .. } if type_name == "brand_new_block")
+        );
+        assert_eq!(result[0].block_id(), Some("new1"));
+        let Block::RichText(rich_text) = &result[1] else {
+            panic!("Expected rich text, got {:?}", result[1]);
+        };
+        let BlockElement::RichTextSection(section) = &rich_text.elements[0] else {
+            panic!("Expected a section, got {:?}", rich_text.elements[0]);
+        };
+        assert!(matches!(
+            &section.elements[..],
+            [BlockElement::Unknown { type_name, .. }, BlockElement::Text(_)]
+                if type_name == "brand_new_element"
+        ));
+    }
+
+    #[test]
+    fn should_round_trip_context_block() {
+        let result = parse_round_trip(CONTEXT_BLOCK);
+
+        assert_eq!(
+            result,
+            vec![Block::Context(
+                ContextBlock::new()
+                    .elements(vec![
+                        ContextElement::Image(ImageElement {
+                            image_url: "https://example.com/pin.png".to_string(),
+                            alt_text: "pin".to_string(),
+                        }),
+                        ContextElement::Markdown(TextBody {
+                            text: "Location: *Dogpatch*".to_string(),
+                            style: None,
+                        }),
+                    ])
+                    .block_id(Some("ctx1".to_string()))
+                    .build()
+            )]
+        )
+    }
+
+    #[test]
+    fn should_round_trip_image_block() {
+        let result = parse_round_trip(IMAGE_BLOCK);
+
+        assert_eq!(
+            result,
+            vec![Block::Image(
+                ImageBlock::new()
+                    .image_url("https://example.com/kitten.png".to_string())
+                    .alt_text("An incredibly cute kitten.".to_string())
+                    .title(Some(Text::PlainText(TextBody {
+                        text: "A kitten".to_string(),
+                        style: None,
+                    })))
+                    .block_id(Some("image4".to_string()))
+                    .build()
+            )]
+        )
+    }
+
+    #[test]
+    fn should_round_trip_section_with_accessory() {
+        let result = parse_round_trip(SECTION_WITH_ACCESSORY);
+
+        assert_eq!(
+            result,
+            vec![Block::Section(
+                SectionBlock::new()
+                    .text(Some(Text::Markdown(TextBody {
+                        text: "*Sally* has requested you set the deadline".to_string(),
+                        style: None,
+                    })))
+                    .accessory(Some(InteractiveElement::Image(ImageElement {
+                        image_url: "https://example.com/sally.png".to_string(),
+                        alt_text: "Sally".to_string(),
+                    })))
+                    .block_id(Some("section567".to_string()))
+                    .build()
+            )]
+        )
+    }
+
+    #[test]
+    fn should_round_trip_input_block() {
+        let result = parse_round_trip(INPUT_BLOCK);
+
+        assert_eq!(
+            result,
+            vec![Block::Input(
+                InputBlock::new()
+                    .label(Text::PlainText(TextBody {
+                        text: "Feedback".to_string(),
+                        style: None,
+                    }))
+                    .element(InteractiveElement::PlainTextInput(
+                        PlainTextInputElement::new()
+                            .action_id(Some("feedback_text".to_string()))
+                            .multiline(Some(true))
+                            .build()
+                    ))
+                    .optional(Some(true))
+                    .block_id(Some("feedback".to_string()))
+                    .build()
+            )]
+        )
+    }
+
+    #[test]
+    fn should_round_trip_file_and_video_blocks() {
+        let result = parse_round_trip(FILE_AND_VIDEO_BLOCKS);
+
+        assert_eq!(
+            result[0],
+            Block::File(
+                FileBlock::new()
+                    .external_id("ABCD1".to_string())
+                    .block_id(Some("file1".to_string()))
+                    .build()
+            )
+        );
+        assert!(
+            matches!(&result[1], Block::Video(video) if video.provider_name.as_deref() == Some("YouTube"))
+        );
+    }
+
+    #[test]
+    fn should_round_trip_buttons_date_picker_and_overflow() {
+        let result = parse_round_trip(APPROVAL_ACTIONS_BLOCK);
+
+        let Block::Actions(actions) = &result[0] else {
+            panic!("Expected an actions block");
+        };
+        assert_eq!(
+            actions.elements[0],
+            InteractiveElement::Button(
+                ButtonElement::new()
+                    .text(Text::PlainText(TextBody {
+                        text: "Approve".to_string(),
+                        style: None,
+                    }))
+                    .action_id(Some("approve".to_string()))
+                    .value(Some("approve".to_string()))
+                    .style(Some(ButtonStyle::Primary))
+                    .confirm(Some(
+                        ConfirmationDialog::new()
+                            .title(Text::PlainText(TextBody {
+                                text: "Are you sure?".to_string(),
+                                style: None,
+                            }))
+                            .text(Text::Markdown(TextBody {
+                                text: "This can't be undone".to_string(),
+                                style: None,
+                            }))
+                            .confirm(Text::PlainText(TextBody {
+                                text: "Do it".to_string(),
+                                style: None,
+                            }))
+                            .deny(Text::PlainText(TextBody {
+                                text: "Stop".to_string(),
+                                style: None,
+                            }))
+                            .build()
+                    ))
+                    .build()
+            )
+        );
+        assert!(matches!(
+            actions.elements[2],
+            InteractiveElement::DatePicker(_)
+        ));
+        assert!(matches!(
+            actions.elements[3],
+            InteractiveElement::Overflow(_)
+        ));
+    }
+
+    #[test]
+    fn should_round_trip_select_menus_and_choices() {
+        let result = parse_round_trip(SELECT_MENU_BLOCKS);
+
+        assert!(matches!(
+            &result[0],
+            Block::Section(SectionBlock {
+                accessory: Some(InteractiveElement::StaticSelect(_)),
+                ..
+            })
+        ));
+        assert!(matches!(
+            &result[1],
+            Block::Input(InputBlock {
+                element: InteractiveElement::MultiUsersSelect(_),
+                ..
+            })
+        ));
+        let Block::Actions(actions) = &result[2] else {
+            panic!("Expected an actions block");
+        };
+        assert_eq!(actions.elements.len(), 11);
+    }
+
+    #[test]
+    fn should_serialise_poll_built_with_builders() {
+        let option = |text: &str| {
+            OptionObject::new()
+                .text(Text::PlainText(TextBody {
+                    text: text.to_string(),
+                    style: None,
+                }))
+                .value(text.to_lowercase())
+                .build()
+        };
+        let poll = Block::Section(
+            SectionBlock::new()
+                .text(Some(Text::Markdown(TextBody {
+                    text: "*Lunch?*".to_string(),
+                    style: None,
+                })))
+                .accessory(Some(InteractiveElement::RadioButtons(
+                    RadioButtonsElement::new()
+                        .options(vec![option("Pizza"), option("Tacos")])
+                        .action_id(Some("lunch_poll".to_string()))
+                        .build(),
+                )))
+                .build(),
+        );
+
+        assert_eq!(
+            serde_json::to_value(&poll).unwrap(),
+            json!({
+                "type": "section",
+                "text": { "type": "mrkdwn", "text": "*Lunch?*" },
+                "accessory": {
+                    "type": "radio_buttons",
+                    "action_id": "lunch_poll",
+                    "options": [
+                        { "text": { "type": "plain_text", "text": "Pizza" }, "value": "pizza" },
+                        { "text": { "type": "plain_text", "text": "Tacos" }, "value": "tacos" }
+                    ]
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn should_round_trip_formatted_rich_text() {
+        let result = parse_round_trip(FORMATTED_RICH_TEXT);
+
+        let Block::RichText(rich_text) = &result[0] else {
+            panic!("Expected a rich text block");
+        };
+        let BlockElement::RichTextSection(section) = &rich_text.elements[0] else {
+            panic!("Expected a rich text section");
+        };
+        assert_eq!(
+            section.elements[0],
+            BlockElement::Broadcast(BroadcastElement {
+                range: BroadcastRange::Here
+            })
+        );
+        assert_eq!(
+            section.elements[2],
+            BlockElement::Text(
+                TextBody::new()
+                    .text("release".to_string())
+                    .style(Some(TextStyle {
+                        bold: true,
+                        italic: true,
+                        ..TextStyle::default()
+                    }))
+                    .build()
+            )
+        );
+        assert_eq!(
+            section.elements[4],
+            BlockElement::Channel(ChannelElement::new().id("C0123".to_string()).build())
+        );
+        assert_eq!(
+            section.elements[8],
+            BlockElement::Link(
+                LinkElement::new()
+                    .url("https://example.com/notes".to_string())
+                    .text(Some("the notes".to_string()))
+                    .build()
+            )
+        );
+        assert!(matches!(
+            &rich_text.elements[1],
+            BlockElement::RichTextList(RichTextListElement {
+                style: ListStyle::Bullet,
+                elements,
+                ..
+            }) if elements.len() == 2
+        ));
+        assert!(matches!(
+            rich_text.elements[2],
+            BlockElement::RichTextQuote(_)
+        ));
+        assert!(matches!(
+            rich_text.elements[3],
+            BlockElement::RichTextPreformatted(_)
+        ));
+    }
+
+    #[test]
+    fn should_build_blocks_with_macro() {
+        let name = "ferris";
+
+        let result = crate::blocks! {
+            header("Emoji changelog"),
+            markdown(":heavy_plus_sign: :{name}: `:{}:`", name),
+            divider,
+            rich_text("{} {}", "hello", name),
+            image("https://example.com/ferris.png", name),
+            block(HeaderBlock::new("The end")),
+        };
+
+        assert_eq!(
+            result,
+            vec![
+                HeaderBlock::new("Emoji changelog"),
+                SectionBlock::new_markdown(":heavy_plus_sign: :ferris: `:ferris:`"),
+                Block::Divider,
+                Block::RichText(
+                    RichTextBlock::new()
+                        .elements(vec![BlockElement::RichTextSection(
+                            RichTextSectionElement::new()
+                                .elements(vec![BlockElement::Text(
+                                    TextBody::new().text("hello ferris".to_string()).build()
+                                )])
+                                .build()
+                        )])
+                        .build()
+                ),
+                Block::Image(
+                    ImageBlock::new()
+                        .image_url("https://example.com/ferris.png".to_string())
+                        .alt_text("ferris".to_string())
+                        .build()
+                ),
+                HeaderBlock::new("The end"),
+            ]
+        )
+    }
 }
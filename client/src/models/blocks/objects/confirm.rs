@@ -0,0 +1,19 @@
+use crate::models::blocks::elements::button::ButtonStyle;
+use crate::models::blocks::objects::text::Text;
+use builder_pattern::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// Asks "are you sure?" before an element's action goes ahead
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct ConfirmationDialog {
+    pub title: Text,
+    pub text: Text,
+    /// The label of the button that goes ahead with the action
+    pub confirm: Text,
+    /// The label of the button that cancels the action
+    pub deny: Text,
+    #[default(None)]
+    pub style: Option<ButtonStyle>,
+}
@@ -1 +1,3 @@
+pub mod confirm;
+pub mod option;
 pub mod text;
@@ -0,0 +1,25 @@
+use crate::models::blocks::objects::text::Text;
+use builder_pattern::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// One of the choices in a select menu, overflow menu, checkbox group or radio button group
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct OptionObject {
+    pub text: Text,
+    /// Sent to the app when this option is chosen
+    pub value: String,
+    #[default(None)]
+    pub description: Option<Text>,
+    /// Only for overflow menus, opens the URL in the user's browser
+    #[default(None)]
+    pub url: Option<String>,
+}
+
+/// Options grouped under a label, for static select menus
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct OptionGroup {
+    pub label: Text,
+    pub options: Vec<OptionObject>,
+}
@@ -12,7 +12,28 @@ pub enum Text {
     Markdown(TextBody),
 }
 
+#[skip_serializing_none]
 #[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
 pub struct TextBody {
     pub text: String,
+    /// Only in rich text, where the text has been formatted
+    #[default(None)]
+    pub style: Option<TextStyle>,
+}
+
+/// How rich text has been formatted, Slack only includes the styles that are applied
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq, Clone, Copy)]
+pub struct TextStyle {
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub bold: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub italic: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub strike: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub code: bool,
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
 }
@@ -0,0 +1,434 @@
+//! Turns blocks back into the text a user sees, as plain text or CommonMark.
+//!
+//! Slack's `text` field is only a fallback, and can differ from what the blocks show, so prefer
+//! rendering the blocks when a message has them.
+
+use crate::models::blocks::context::ContextElement;
+use crate::models::blocks::elements::broadcast::BroadcastRange;
+use crate::models::blocks::elements::emoji::EmojiElement;
+use crate::models::blocks::elements::text::ListStyle;
+use crate::models::blocks::elements::BlockElement;
+use crate::models::blocks::objects::text::{Text, TextBody, TextStyle};
+use crate::models::blocks::Block;
+use std::collections::HashMap;
+
+/// Looks up the names of users, channels and user groups mentioned in rich text.
+///
+/// Implemented for a map of IDs to names, for when they've been looked up beforehand.
+pub trait Names {
+    fn user(&self, id: &str) -> Option<String>;
+    fn channel(&self, id: &str) -> Option<String>;
+    fn usergroup(&self, id: &str) -> Option<String>;
+}
+
+impl Names for HashMap<String, String> {
+    fn user(&self, id: &str) -> Option<String> {
+        self.get(id).cloned()
+    }
+
+    fn channel(&self, id: &str) -> Option<String> {
+        self.get(id).cloned()
+    }
+
+    fn usergroup(&self, id: &str) -> Option<String> {
+        self.get(id).cloned()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    PlainText,
+    /// CommonMark, plus `~~strikethrough~~`
+    Markdown,
+}
+
+/// Renders blocks as text.
+///
+/// Mentions are shown by ID, e.g. `@U123`, unless names are given with [`Renderer::with_names`].
+/// `mrkdwn` text objects are passed through as they are, while `plain_text` ones are escaped when
+/// rendering Markdown.
+pub struct Renderer<'a> {
+    format: Format,
+    names: Option<&'a dyn Names>,
+}
+
+impl<'a> Renderer<'a> {
+    pub fn new(format: Format) -> Self {
+        Self {
+            format,
+            names: None,
+        }
+    }
+
+    pub fn plain_text() -> Self {
+        Self::new(Format::PlainText)
+    }
+
+    pub fn markdown() -> Self {
+        Self::new(Format::Markdown)
+    }
+
+    pub fn with_names(mut self, names: &'a dyn Names) -> Self {
+        self.names = Some(names);
+        self
+    }
+
+    pub fn render(&self, blocks: &[Block]) -> String {
+        let separator = match self.format {
+            Format::PlainText => "\n",
+            Format::Markdown => "\n\n",
+        };
+        blocks
+            .iter()
+            .filter_map(|block| self.render_block(block))
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+
+    /// Render a single block, `None` for blocks that show no text such as input blocks
+    pub fn render_block(&self, block: &Block) -> Option<String> {
+        match block {
+            Block::Header(header) => {
+                let text = self.text(&header.text);
+                Some(match self.format {
+                    Format::PlainText => text,
+                    Format::Markdown => format!("# {text}"),
+                })
+            }
+            Block::Section(section) => {
+                let mut lines: Vec<String> =
+                    section.text.iter().map(|text| self.text(text)).collect();
+                lines.extend(section.fields.iter().flatten().map(|text| self.text(text)));
+                Some(lines.join("\n"))
+            }
+            Block::Context(context) => Some(
+                context
+                    .elements
+                    .iter()
+                    .map(|element| match element {
+                        ContextElement::PlainText(body) => self.unformatted(&body.text),
+                        ContextElement::Markdown(body) => body.text.clone(),
+                        ContextElement::Image(image) => {
+                            self.image(&image.alt_text, &image.image_url)
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            ),
+            Block::Divider => Some("---".to_string()),
+            Block::Image(image) => Some(self.image(&image.alt_text, &image.image_url)),
+            Block::Video(video) => Some(self.link(text_of(&video.title), &video.video_url)),
+            Block::RichText(rich_text) => Some(self.render_elements(&rich_text.elements)),
+            Block::Actions(_) | Block::Input(_) | Block::File(_) | Block::Unknown { .. } => None,
+        }
+    }
+
+    /// Render rich text elements
+    pub fn render_elements(&self, elements: &[BlockElement]) -> String {
+        let mut output = String::new();
+        for element in elements {
+            self.render_element(element, &mut output);
+        }
+        output.trim_end_matches('\n').to_string()
+    }
+
+    fn render_element(&self, element: &BlockElement, output: &mut String) {
+        match element {
+            BlockElement::RichTextSection(section) => {
+                for element in &section.elements {
+                    self.render_element(element, output);
+                }
+            }
+            BlockElement::RichTextList(list) => {
+                end_line(output);
+                let indent = "    ".repeat(list.indent.unwrap_or(0) as usize);
+                for (i, item) in list.elements.iter().enumerate() {
+                    let marker = match (list.style, self.format) {
+                        (ListStyle::Bullet, Format::PlainText) => "•".to_string(),
+                        (ListStyle::Bullet, Format::Markdown) => "-".to_string(),
+                        (ListStyle::Ordered, _) => {
+                            format!("{}.", list.offset.unwrap_or(0) as usize + i + 1)
+                        }
+                    };
+                    output.push_str(&format!("{indent}{marker} "));
+                    self.render_element(item, output);
+                    end_line(output);
+                }
+            }
+            BlockElement::RichTextQuote(quote) => {
+                end_line(output);
+                let text = self.render_elements(&quote.elements);
+                match self.format {
+                    Format::PlainText => output.push_str(&text),
+                    Format::Markdown => output.push_str(
+                        &text
+                            .lines()
+                            .map(|line| format!("> {line}"))
+                            .collect::<Vec<_>>()
+                            .join("\n"),
+                    ),
+                }
+                output.push('\n');
+            }
+            BlockElement::RichTextPreformatted(preformatted) => {
+                end_line(output);
+                // Code isn't styled or escaped, so render it as plain text whatever the format
+                let text = Renderer {
+                    format: Format::PlainText,
+                    names: self.names,
+                }
+                .render_elements(&preformatted.elements);
+                match self.format {
+                    Format::PlainText => output.push_str(&text),
+                    Format::Markdown => output.push_str(&format!("```\n{text}\n```")),
+                }
+                output.push('\n');
+            }
+            BlockElement::Text(text) => output.push_str(&self.styled(text)),
+            BlockElement::Link(link) => match &link.text {
+                Some(text) => output.push_str(&self.link(text, &link.url)),
+                None => output.push_str(&self.url(&link.url)),
+            },
+            BlockElement::Emoji(emoji) => output.push_str(&render_emoji(emoji)),
+            BlockElement::User(user) => {
+                let name = self.names.and_then(|names| names.user(&user.id));
+                output.push_str(&format!("@{}", name.unwrap_or_else(|| user.id.clone())));
+            }
+            BlockElement::Channel(channel) => {
+                let name = self.names.and_then(|names| names.channel(&channel.id));
+                output.push_str(&format!("#{}", name.unwrap_or_else(|| channel.id.clone())));
+            }
+            BlockElement::Usergroup(usergroup) => {
+                let name = self.names.and_then(|names| names.usergroup(&usergroup.id));
+                output.push_str(&format!(
+                    "@{}",
+                    name.unwrap_or_else(|| usergroup.id.clone())
+                ));
+            }
+            BlockElement::Broadcast(broadcast) => output.push_str(match broadcast.range {
+                BroadcastRange::Here => "@here",
+                BroadcastRange::Channel => "@channel",
+                BroadcastRange::Everyone => "@everyone",
+            }),
+            BlockElement::Date(date) => match &date.fallback {
+                Some(fallback) => output.push_str(fallback),
+                None => output.push_str(&date.timestamp.to_string()),
+            },
+            BlockElement::Color(color) => output.push_str(&color.value),
+            BlockElement::Unknown { .. } => {}
+        }
+    }
+
+    fn styled(&self, text: &TextBody) -> String {
+        let style = match (self.format, text.style) {
+            (Format::Markdown, Some(style)) => style,
+            (Format::Markdown, None) => return escape(&text.text),
+            (Format::PlainText, _) => return text.text.clone(),
+        };
+
+        // Markdown emphasis can't start or end with whitespace, so keep it outside the markers
+        let trimmed = text.text.trim();
+        if trimmed.is_empty() {
+            return text.text.clone();
+        }
+        let leading = &text.text[..text.text.len() - text.text.trim_start().len()];
+        let trailing = &text.text[text.text.trim_end().len()..];
+        let TextStyle {
+            bold,
+            italic,
+            strike,
+            code,
+        } = style;
+        let mut styled = if code {
+            format!("`{trimmed}`")
+        } else {
+            escape(trimmed)
+        };
+        if italic {
+            styled = format!("_{styled}_");
+        }
+        if bold {
+            styled = format!("**{styled}**");
+        }
+        if strike {
+            styled = format!("~~{styled}~~");
+        }
+        format!("{leading}{styled}{trailing}")
+    }
+
+    /// `plain_text` is escaped so it isn't read as Markdown, `mrkdwn` is passed through
+    fn text(&self, text: &Text) -> String {
+        match text {
+            Text::PlainText(body) => self.unformatted(&body.text),
+            Text::Markdown(body) => body.text.clone(),
+        }
+    }
+
+    fn unformatted(&self, text: &str) -> String {
+        match self.format {
+            Format::PlainText => text.to_string(),
+            Format::Markdown => escape(text),
+        }
+    }
+
+    fn link(&self, text: &str, url: &str) -> String {
+        match self.format {
+            Format::PlainText => format!("{text} ({url})"),
+            Format::Markdown => format!("[{}]({url})", escape(text)),
+        }
+    }
+
+    fn url(&self, url: &str) -> String {
+        match self.format {
+            Format::PlainText => url.to_string(),
+            Format::Markdown => format!("<{url}>"),
+        }
+    }
+
+    fn image(&self, alt_text: &str, url: &str) -> String {
+        match self.format {
+            Format::PlainText => alt_text.to_string(),
+            Format::Markdown => format!("![{}]({url})", escape(alt_text)),
+        }
+    }
+}
+
+fn text_of(text: &Text) -> &str {
+    match text {
+        Text::PlainText(body) | Text::Markdown(body) => &body.text,
+    }
+}
+
+/// End the current line, if anything has been written on it
+fn end_line(output: &mut String) {
+    if !output.is_empty() && !output.ends_with('\n') {
+        output.push('\n');
+    }
+}
+
+/// The emoji itself if Slack told us its code points, otherwise its `:name:`
+fn render_emoji(emoji: &EmojiElement) -> String {
+    emoji
+        .unicode
+        .as_ref()
+        .and_then(|unicode| {
+            unicode
+                .split('-')
+                .map(|code_point| {
+                    u32::from_str_radix(code_point, 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                })
+                .collect::<Option<String>>()
+        })
+        .unwrap_or_else(|| format!(":{}:", emoji.name))
+}
+
+/// Escape characters that CommonMark would treat as formatting
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        if matches!(
+            character,
+            '\\' | '*' | '_' | '`' | '[' | ']' | '~' | '<' | '>' | '#'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(character);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blocks;
+
+    const RICH_TEXT: &str = r#"{"type":"rich_text","elements":[
+        {"type":"rich_text_section","elements":[
+            {"type":"text","text":"Hi "},
+            {"type":"user","user_id":"U123"},
+            {"type":"text","text":" look at ","style":{"bold":true}},
+            {"type":"link","url":"https://example.com","text":"this"},
+            {"type":"emoji","name":"thumbsup","unicode":"1f44d"},
+            {"type":"emoji","name":"ferris"}
+        ]},
+        {"type":"rich_text_list","style":"ordered","elements":[
+            {"type":"rich_text_section","elements":[{"type":"text","text":"one"}]},
+            {"type":"rich_text_section","elements":[{"type":"text","text":"two_three"}]}
+        ]},
+        {"type":"rich_text_quote","elements":[{"type":"text","text":"quoted"}]},
+        {"type":"rich_text_preformatted","elements":[{"type":"text","text":"let x = *y;"}]}
+    ]}"#;
+
+    fn rich_text() -> Vec<Block> {
+        vec![serde_json::from_str(RICH_TEXT).unwrap()]
+    }
+
+    #[test]
+    fn should_render_rich_text_as_plain_text() {
+        let rendered = Renderer::plain_text().render(&rich_text());
+
+        assert_eq!(
+            rendered,
+            "Hi @U123 look at this (https://example.com)👍:ferris:\n\
+            1. one\n\
+            2. two_three\n\
+            quoted\n\
+            let x = *y;"
+        );
+    }
+
+    #[test]
+    fn should_render_rich_text_as_markdown() {
+        let rendered = Renderer::markdown().render(&rich_text());
+
+        assert_eq!(
+            rendered,
+            "Hi @U123 **look at** [this](https://example.com)👍:ferris:\n\
+            1. one\n\
+            2. two\\_three\n\
+            > quoted\n\
+            ```\nlet x = *y;\n```"
+        );
+    }
+
+    #[test]
+    fn should_escape_plain_text_but_not_mrkdwn_in_markdown() {
+        let blocks = blocks! {
+            header("#1 *deal*"),
+            markdown("*bold* _on purpose_"),
+        };
+
+        let rendered = Renderer::markdown().render(&blocks);
+
+        assert_eq!(rendered, "# \\#1 \\*deal\\*\n\n*bold* _on purpose_");
+    }
+
+    #[test]
+    fn given_names_should_render_mentions_by_name() {
+        let names = HashMap::from([("U123".to_string(), "ferris".to_string())]);
+
+        let rendered = Renderer::plain_text()
+            .with_names(&names)
+            .render(&rich_text());
+
+        assert!(rendered.starts_with("Hi @ferris look at"));
+    }
+
+    #[test]
+    fn should_render_blocks_separated_by_blank_lines_in_markdown() {
+        let blocks = blocks! {
+            header("Emoji changelog"),
+            markdown(":heavy_plus_sign: :ferris:"),
+            divider,
+        };
+
+        let rendered = Renderer::markdown().render(&blocks);
+
+        assert_eq!(
+            rendered,
+            "# Emoji changelog\n\n:heavy_plus_sign: :ferris:\n\n---"
+        );
+    }
+}
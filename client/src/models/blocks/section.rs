@@ -1,3 +1,4 @@
+use crate::models::blocks::elements::interactive::InteractiveElement;
 use crate::models::blocks::objects::text::{Text, TextBody};
 use crate::models::blocks::Block;
 use builder_pattern::Builder;
@@ -11,6 +12,11 @@ pub struct SectionBlock {
     pub text: Option<Text>,
     #[default(None)]
     pub fields: Option<Vec<Text>>,
+    /// Shown beside the text, e.g. an image or a button
+    #[default(None)]
+    pub accessory: Option<InteractiveElement>,
+    #[default(None)]
+    pub block_id: Option<String>,
 }
 
 impl SectionBlock {
@@ -18,8 +24,11 @@ impl SectionBlock {
         Block::Section(SectionBlock {
             text: Some(Text::Markdown(TextBody {
                 text: markdown.to_string(),
+                style: None,
             })),
             fields: None,
+            accessory: None,
+            block_id: None,
         })
     }
 }
@@ -9,6 +9,8 @@ use serde_with::skip_serializing_none;
 #[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
 pub struct RichTextBlock {
     pub elements: Vec<BlockElement>,
+    #[default(None)]
+    pub block_id: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -22,6 +24,7 @@ impl HeaderBlock {
         Block::Header(HeaderBlock {
             text: Text::PlainText(TextBody {
                 text: text.to_string(),
+                style: None,
             }),
         })
     }
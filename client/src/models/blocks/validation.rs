@@ -0,0 +1,508 @@
+//! Checks blocks against Block Kit's limits, so that messages Slack would reject with an opaque
+//! `invalid_blocks` error are caught before they're sent.
+
+use crate::models::blocks::elements::interactive::InteractiveElement;
+use crate::models::blocks::objects::confirm::ConfirmationDialog;
+use crate::models::blocks::objects::option::{OptionGroup, OptionObject};
+use crate::models::blocks::objects::text::Text;
+use crate::models::blocks::Block;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+
+const MAX_BLOCKS: usize = 50;
+const MAX_ID: usize = 255;
+const MAX_HEADER_TEXT: usize = 150;
+const MAX_SECTION_TEXT: usize = 3000;
+const MAX_SECTION_FIELDS: usize = 10;
+const MAX_FIELD_TEXT: usize = 2000;
+const MAX_CONTEXT_ELEMENTS: usize = 10;
+const MAX_ACTIONS_ELEMENTS: usize = 25;
+const MAX_LABEL_TEXT: usize = 2000;
+const MAX_ALT_TEXT: usize = 2000;
+const MAX_URL: usize = 3000;
+const MAX_VIDEO_TITLE: usize = 200;
+const MAX_BUTTON_TEXT: usize = 75;
+const MAX_BUTTON_VALUE: usize = 2000;
+const MAX_PLACEHOLDER: usize = 150;
+const MAX_SELECT_OPTIONS: usize = 100;
+const MAX_OVERFLOW_OPTIONS: usize = 5;
+const MAX_CHOICE_OPTIONS: usize = 10;
+const MAX_OPTION_TEXT: usize = 75;
+const MAX_OPTION_VALUE: usize = 150;
+const MAX_CONFIRM_TITLE: usize = 100;
+const MAX_CONFIRM_TEXT: usize = 300;
+const MAX_CONFIRM_BUTTON: usize = 30;
+
+/// A Block Kit limit that a message breaks
+#[derive(Debug, PartialEq, Clone)]
+pub struct ValidationError {
+    /// Where in the message the problem is, e.g. `blocks[2].fields[10]`
+    pub path: String,
+    pub kind: ValidationErrorKind,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ValidationErrorKind {
+    /// The message has no text or blocks, or a section has no text or fields
+    Empty,
+    /// Text or a URL has more characters than Slack allows
+    TooLong {
+        max: usize,
+        actual: usize,
+    },
+    TooMany {
+        max: usize,
+        actual: usize,
+    },
+    TooFew {
+        min: usize,
+        actual: usize,
+    },
+    /// Block IDs must be unique in a message, and action IDs in a block
+    Duplicate(String),
+}
+
+impl ValidationError {
+    pub fn new(path: &str, kind: ValidationErrorKind) -> Self {
+        Self {
+            path: path.to_string(),
+            kind,
+        }
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            ValidationErrorKind::Empty => write!(f, "{} is empty", self.path),
+            ValidationErrorKind::TooLong { max, actual } => write!(
+                f,
+                "{} is {} characters, the most allowed is {}",
+                self.path, actual, max
+            ),
+            ValidationErrorKind::TooMany { max, actual } => write!(
+                f,
+                "{} has {} items, the most allowed is {}",
+                self.path, actual, max
+            ),
+            ValidationErrorKind::TooFew { min, actual } => write!(
+                f,
+                "{} has {} items, the fewest allowed is {}",
+                self.path, actual, min
+            ),
+            ValidationErrorKind::Duplicate(id) => write!(f, "{} repeats ID {}", self.path, id),
+        }
+    }
+}
+
+/// Check `blocks` against Block Kit's limits, returning every problem found
+pub fn validate_blocks(blocks: &[Block]) -> Result<(), Vec<ValidationError>> {
+    let mut validator = Validator::default();
+    validator.max_items("blocks", blocks.len(), MAX_BLOCKS);
+    let mut block_ids = HashSet::new();
+    for (i, block) in blocks.iter().enumerate() {
+        let path = format!("blocks[{i}]");
+        if let Some(block_id) = block.block_id() {
+            validator.max_length(&format!("{path}.block_id"), block_id, MAX_ID);
+            if !block_ids.insert(block_id) {
+                validator.error(
+                    &format!("{path}.block_id"),
+                    ValidationErrorKind::Duplicate(block_id.to_string()),
+                );
+            }
+        }
+        validator.block(&path, block);
+    }
+
+    if validator.errors.is_empty() {
+        Ok(())
+    } else {
+        Err(validator.errors)
+    }
+}
+
+#[derive(Default)]
+struct Validator {
+    errors: Vec<ValidationError>,
+}
+
+impl Validator {
+    fn error(&mut self, path: &str, kind: ValidationErrorKind) {
+        self.errors.push(ValidationError::new(path, kind));
+    }
+
+    fn max_length(&mut self, path: &str, value: &str, max: usize) {
+        let actual = value.chars().count();
+        if actual > max {
+            self.error(path, ValidationErrorKind::TooLong { max, actual });
+        }
+    }
+
+    fn max_items(&mut self, path: &str, actual: usize, max: usize) {
+        if actual > max {
+            self.error(path, ValidationErrorKind::TooMany { max, actual });
+        }
+    }
+
+    fn min_items(&mut self, path: &str, actual: usize, min: usize) {
+        if actual < min {
+            self.error(path, ValidationErrorKind::TooFew { min, actual });
+        }
+    }
+
+    fn text(&mut self, path: &str, text: &Text, max: usize) {
+        let body = match text {
+            Text::PlainText(body) | Text::Markdown(body) => body,
+        };
+        self.max_length(&format!("{path}.text"), &body.text, max);
+    }
+
+    fn optional_text(&mut self, path: &str, text: &Option<Text>, max: usize) {
+        if let Some(text) = text {
+            self.text(path, text, max);
+        }
+    }
+
+    fn optional_value(&mut self, path: &str, value: &Option<String>, max: usize) {
+        if let Some(value) = value {
+            self.max_length(path, value, max);
+        }
+    }
+
+    fn block(&mut self, path: &str, block: &Block) {
+        match block {
+            Block::Header(header) => {
+                self.text(&format!("{path}.text"), &header.text, MAX_HEADER_TEXT)
+            }
+            Block::Section(section) => {
+                if section.text.is_none() && section.fields.is_none() {
+                    self.error(path, ValidationErrorKind::Empty);
+                }
+                self.optional_text(&format!("{path}.text"), &section.text, MAX_SECTION_TEXT);
+                if let Some(fields) = &section.fields {
+                    self.max_items(&format!("{path}.fields"), fields.len(), MAX_SECTION_FIELDS);
+                    for (i, field) in fields.iter().enumerate() {
+                        self.text(&format!("{path}.fields[{i}]"), field, MAX_FIELD_TEXT);
+                    }
+                }
+                if let Some(accessory) = &section.accessory {
+                    self.element(&format!("{path}.accessory"), accessory);
+                }
+            }
+            Block::Context(context) => {
+                self.min_items(&format!("{path}.elements"), context.elements.len(), 1);
+                self.max_items(
+                    &format!("{path}.elements"),
+                    context.elements.len(),
+                    MAX_CONTEXT_ELEMENTS,
+                );
+            }
+            Block::Actions(actions) => {
+                self.min_items(&format!("{path}.elements"), actions.elements.len(), 1);
+                self.max_items(
+                    &format!("{path}.elements"),
+                    actions.elements.len(),
+                    MAX_ACTIONS_ELEMENTS,
+                );
+                let mut action_ids = HashSet::new();
+                for (i, element) in actions.elements.iter().enumerate() {
+                    let element_path = format!("{path}.elements[{i}]");
+                    if let Some(action_id) = element.action_id() {
+                        if !action_ids.insert(action_id) {
+                            self.error(
+                                &format!("{element_path}.action_id"),
+                                ValidationErrorKind::Duplicate(action_id.to_string()),
+                            );
+                        }
+                    }
+                    self.element(&element_path, element);
+                }
+            }
+            Block::Input(input) => {
+                self.text(&format!("{path}.label"), &input.label, MAX_LABEL_TEXT);
+                self.optional_text(&format!("{path}.hint"), &input.hint, MAX_LABEL_TEXT);
+                self.element(&format!("{path}.element"), &input.element);
+            }
+            Block::Image(image) => {
+                self.max_length(&format!("{path}.image_url"), &image.image_url, MAX_URL);
+                self.max_length(&format!("{path}.alt_text"), &image.alt_text, MAX_ALT_TEXT);
+                self.optional_text(&format!("{path}.title"), &image.title, MAX_ALT_TEXT);
+            }
+            Block::Video(video) => {
+                self.text(&format!("{path}.title"), &video.title, MAX_VIDEO_TITLE);
+                self.max_length(&format!("{path}.video_url"), &video.video_url, MAX_URL);
+                self.max_length(
+                    &format!("{path}.thumbnail_url"),
+                    &video.thumbnail_url,
+                    MAX_URL,
+                );
+            }
+            Block::RichText(_) | Block::Divider | Block::File(_) | Block::Unknown { .. } => {}
+        }
+    }
+
+    fn element(&mut self, path: &str, element: &InteractiveElement) {
+        if let Some(action_id) = element.action_id() {
+            self.max_length(&format!("{path}.action_id"), action_id, MAX_ID);
+        }
+        let placeholder = format!("{path}.placeholder");
+        let confirm = format!("{path}.confirm");
+        match element {
+            InteractiveElement::Image(image) => {
+                self.max_length(&format!("{path}.image_url"), &image.image_url, MAX_URL);
+                self.max_length(&format!("{path}.alt_text"), &image.alt_text, MAX_ALT_TEXT);
+            }
+            InteractiveElement::PlainTextInput(input) => {
+                self.optional_text(&placeholder, &input.placeholder, MAX_PLACEHOLDER);
+            }
+            InteractiveElement::Button(button) => {
+                self.text(&format!("{path}.text"), &button.text, MAX_BUTTON_TEXT);
+                self.optional_value(&format!("{path}.value"), &button.value, MAX_BUTTON_VALUE);
+                self.optional_value(&format!("{path}.url"), &button.url, MAX_URL);
+                self.confirm(&confirm, &button.confirm);
+            }
+            InteractiveElement::StaticSelect(select) => {
+                self.optional_text(&placeholder, &select.placeholder, MAX_PLACEHOLDER);
+                self.select_options(path, &select.options, &select.option_groups);
+                self.confirm(&confirm, &select.confirm);
+            }
+            InteractiveElement::MultiStaticSelect(select) => {
+                self.optional_text(&placeholder, &select.placeholder, MAX_PLACEHOLDER);
+                self.select_options(path, &select.options, &select.option_groups);
+                self.confirm(&confirm, &select.confirm);
+            }
+            InteractiveElement::ExternalSelect(select) => {
+                self.optional_text(&placeholder, &select.placeholder, MAX_PLACEHOLDER);
+                self.confirm(&confirm, &select.confirm);
+            }
+            InteractiveElement::MultiExternalSelect(select) => {
+                self.optional_text(&placeholder, &select.placeholder, MAX_PLACEHOLDER);
+                self.confirm(&confirm, &select.confirm);
+            }
+            InteractiveElement::UsersSelect(select) => {
+                self.optional_text(&placeholder, &select.placeholder, MAX_PLACEHOLDER);
+                self.confirm(&confirm, &select.confirm);
+            }
+            InteractiveElement::MultiUsersSelect(select) => {
+                self.optional_text(&placeholder, &select.placeholder, MAX_PLACEHOLDER);
+                self.confirm(&confirm, &select.confirm);
+            }
+            InteractiveElement::ConversationsSelect(select) => {
+                self.optional_text(&placeholder, &select.placeholder, MAX_PLACEHOLDER);
+                self.confirm(&confirm, &select.confirm);
+            }
+            InteractiveElement::MultiConversationsSelect(select) => {
+                self.optional_text(&placeholder, &select.placeholder, MAX_PLACEHOLDER);
+                self.confirm(&confirm, &select.confirm);
+            }
+            InteractiveElement::ChannelsSelect(select) => {
+                self.optional_text(&placeholder, &select.placeholder, MAX_PLACEHOLDER);
+                self.confirm(&confirm, &select.confirm);
+            }
+            InteractiveElement::MultiChannelsSelect(select) => {
+                self.optional_text(&placeholder, &select.placeholder, MAX_PLACEHOLDER);
+                self.confirm(&confirm, &select.confirm);
+            }
+            InteractiveElement::DatePicker(picker) => {
+                self.optional_text(&placeholder, &picker.placeholder, MAX_PLACEHOLDER);
+                self.confirm(&confirm, &picker.confirm);
+            }
+            InteractiveElement::TimePicker(picker) => {
+                self.optional_text(&placeholder, &picker.placeholder, MAX_PLACEHOLDER);
+                self.confirm(&confirm, &picker.confirm);
+            }
+            InteractiveElement::Overflow(overflow) => {
+                self.options(path, &overflow.options, 1, MAX_OVERFLOW_OPTIONS);
+                self.confirm(&confirm, &overflow.confirm);
+            }
+            InteractiveElement::Checkboxes(checkboxes) => {
+                self.options(path, &checkboxes.options, 1, MAX_CHOICE_OPTIONS);
+                self.confirm(&confirm, &checkboxes.confirm);
+            }
+            InteractiveElement::RadioButtons(radio_buttons) => {
+                self.options(path, &radio_buttons.options, 1, MAX_CHOICE_OPTIONS);
+                self.confirm(&confirm, &radio_buttons.confirm);
+            }
+        }
+    }
+
+    fn select_options(
+        &mut self,
+        path: &str,
+        options: &Option<Vec<OptionObject>>,
+        option_groups: &Option<Vec<OptionGroup>>,
+    ) {
+        if let Some(options) = options {
+            self.options(path, options, 0, MAX_SELECT_OPTIONS);
+        }
+        if let Some(groups) = option_groups {
+            self.max_items(
+                &format!("{path}.option_groups"),
+                groups.len(),
+                MAX_SELECT_OPTIONS,
+            );
+            for (i, group) in groups.iter().enumerate() {
+                let group_path = format!("{path}.option_groups[{i}]");
+                self.text(
+                    &format!("{group_path}.label"),
+                    &group.label,
+                    MAX_OPTION_TEXT,
+                );
+                self.options(&group_path, &group.options, 0, MAX_SELECT_OPTIONS);
+            }
+        }
+    }
+
+    fn options(&mut self, path: &str, options: &[OptionObject], min: usize, max: usize) {
+        let options_path = format!("{path}.options");
+        self.min_items(&options_path, options.len(), min);
+        self.max_items(&options_path, options.len(), max);
+        for (i, option) in options.iter().enumerate() {
+            let option_path = format!("{options_path}[{i}]");
+            self.text(
+                &format!("{option_path}.text"),
+                &option.text,
+                MAX_OPTION_TEXT,
+            );
+            self.max_length(
+                &format!("{option_path}.value"),
+                &option.value,
+                MAX_OPTION_VALUE,
+            );
+        }
+    }
+
+    fn confirm(&mut self, path: &str, confirm: &Option<ConfirmationDialog>) {
+        if let Some(confirm) = confirm {
+            self.text(&format!("{path}.title"), &confirm.title, MAX_CONFIRM_TITLE);
+            self.text(&format!("{path}.text"), &confirm.text, MAX_CONFIRM_TEXT);
+            self.text(
+                &format!("{path}.confirm"),
+                &confirm.confirm,
+                MAX_CONFIRM_BUTTON,
+            );
+            self.text(&format!("{path}.deny"), &confirm.deny, MAX_CONFIRM_BUTTON);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blocks;
+    use crate::models::blocks::actions::ActionsBlock;
+    use crate::models::blocks::elements::button::ButtonElement;
+    use crate::models::blocks::objects::text::TextBody;
+    use crate::models::blocks::section::SectionBlock;
+
+    fn button(action_id: &str) -> InteractiveElement {
+        InteractiveElement::Button(
+            ButtonElement::new()
+                .text(Text::PlainText(
+                    TextBody::new().text("Go".to_string()).build(),
+                ))
+                .action_id(Some(action_id.to_string()))
+                .build(),
+        )
+    }
+
+    #[test]
+    fn should_accept_blocks_within_limits() {
+        let blocks = blocks! {
+            header("Emoji changelog"),
+            markdown("Hello"),
+            divider,
+        };
+
+        let result = validate_blocks(&blocks);
+
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn given_too_many_blocks_should_return_error() {
+        let blocks: Vec<Block> = (0..51).map(|_| Block::Divider).collect();
+
+        let result = validate_blocks(&blocks);
+
+        assert_eq!(
+            result,
+            Err(vec![ValidationError::new(
+                "blocks",
+                ValidationErrorKind::TooMany {
+                    max: 50,
+                    actual: 51
+                }
+            )])
+        );
+    }
+
+    #[test]
+    fn should_point_at_each_block_over_a_limit() {
+        let long_header = "h".repeat(151);
+        let too_many_fields = (0..11)
+            .map(|_| Text::PlainText(TextBody::new().text("f".to_string()).build()))
+            .collect();
+
+        let blocks = blocks! {
+            markdown("fine"),
+            header("{long_header}"),
+            block(Block::Section(SectionBlock::new().fields(Some(too_many_fields)).build())),
+        };
+
+        let result = validate_blocks(&blocks);
+
+        assert_eq!(
+            result,
+            Err(vec![
+                ValidationError::new(
+                    "blocks[1].text.text",
+                    ValidationErrorKind::TooLong {
+                        max: 150,
+                        actual: 151
+                    }
+                ),
+                ValidationError::new(
+                    "blocks[2].fields",
+                    ValidationErrorKind::TooMany {
+                        max: 10,
+                        actual: 11
+                    }
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn given_duplicate_action_ids_in_block_should_return_error() {
+        let result = validate_blocks(&[Block::Actions(
+            ActionsBlock::new()
+                .elements(vec![button("approve"), button("approve")])
+                .build(),
+        )]);
+
+        assert_eq!(
+            result,
+            Err(vec![ValidationError::new(
+                "blocks[0].elements[1].action_id",
+                ValidationErrorKind::Duplicate("approve".to_string())
+            )])
+        );
+    }
+
+    #[test]
+    fn should_describe_where_the_problem_is() {
+        let error = ValidationError::new(
+            "blocks[3].text.text",
+            ValidationErrorKind::TooLong {
+                max: 3000,
+                actual: 3001,
+            },
+        );
+
+        assert_eq!(
+            error.to_string(),
+            "blocks[3].text.text is 3001 characters, the most allowed is 3000"
+        );
+    }
+}
@@ -0,0 +1,26 @@
+use crate::models::blocks::objects::text::Text;
+use builder_pattern::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// An embedded video player, the `video_url` must be on one of the app's unfurl domains
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Builder)]
+pub struct VideoBlock {
+    pub title: Text,
+    pub video_url: String,
+    pub thumbnail_url: String,
+    pub alt_text: String,
+    #[default(None)]
+    pub title_url: Option<String>,
+    #[default(None)]
+    pub description: Option<Text>,
+    #[default(None)]
+    pub author_name: Option<String>,
+    #[default(None)]
+    pub provider_name: Option<String>,
+    #[default(None)]
+    pub provider_icon_url: Option<String>,
+    #[default(None)]
+    pub block_id: Option<String>,
+}
@@ -0,0 +1,51 @@
+use crate::models::response_metadata::ResponseMetadata;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// A conversation: a public or private channel, a direct message or a group direct message.
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
+pub struct Channel {
+    pub id: String,
+    /// Direct messages have no name
+    pub name: Option<String>,
+    pub created: Option<u64>,
+    pub creator: Option<String>,
+    #[serde(default)]
+    pub is_private: bool,
+    #[serde(default)]
+    pub is_archived: bool,
+    #[serde(default)]
+    pub is_im: bool,
+}
+
+/// Models a message received from the `conversations.info` endpoint.
+#[derive(Debug, Deserialize)]
+pub struct ChannelInfoResponse {
+    pub channel: Channel,
+}
+
+/// Models a message received from the `conversations.list` endpoint.
+#[derive(Debug, Deserialize)]
+pub struct ChannelListResponse {
+    #[serde(default)]
+    pub channels: Vec<Channel>,
+    pub response_metadata: Option<ResponseMetadata>,
+}
+
+/// A page of channels in the workspace.
+#[derive(Debug, PartialEq)]
+pub struct ChannelPage {
+    pub channels: Vec<Channel>,
+    /// Pass this back to the client to fetch the next page, `None` if this is the last page.
+    pub next_cursor: Option<String>,
+}
+
+impl ChannelListResponse {
+    pub fn into_page(self) -> ChannelPage {
+        ChannelPage {
+            channels: self.channels,
+            next_cursor: ResponseMetadata::into_next_cursor(self.response_metadata),
+        }
+    }
+}
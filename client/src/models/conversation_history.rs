@@ -0,0 +1,101 @@
+use crate::models::blocks::Block;
+use crate::models::message_id::MessageId;
+use crate::models::response_metadata::ResponseMetadata;
+use crate::models::socket_message::MessageEvent;
+use serde::Deserialize;
+
+/// Models a message received from the `conversations.history` or `conversations.replies`
+/// endpoints.
+#[derive(Debug, Deserialize)]
+pub struct ConversationHistoryResponse {
+    #[serde(default)]
+    pub messages: Vec<HistoryMessage>,
+    pub response_metadata: Option<ResponseMetadata>,
+}
+
+/// A message as returned by the history endpoints.
+///
+/// Unlike socket mode events these only carry `ts`, and not the channel they were posted in.
+#[derive(Debug, Deserialize)]
+pub struct HistoryMessage {
+    pub ts: MessageId,
+    pub text: Option<String>,
+    pub user: Option<String>,
+    pub blocks: Option<Vec<Block>>,
+}
+
+/// A page of messages from a conversation.
+#[derive(Debug, PartialEq)]
+pub struct MessagePage {
+    pub messages: Vec<MessageEvent>,
+    /// Pass this back to the client to fetch the next page, `None` if this is the last page.
+    pub next_cursor: Option<String>,
+}
+
+impl HistoryMessage {
+    pub fn into_message_event(self, channel: &str) -> MessageEvent {
+        MessageEvent {
+            ts: Some(self.ts.clone()),
+            text: self.text,
+            user: self.user,
+            blocks: self.blocks,
+            channel: Some(channel.to_string()),
+            channel_type: None,
+            ..MessageEvent::new(self.ts)
+        }
+    }
+}
+
+impl ConversationHistoryResponse {
+    pub fn into_page(self, channel: &str) -> MessagePage {
+        MessagePage {
+            messages: self
+                .messages
+                .into_iter()
+                .map(|message| message.into_message_event(channel))
+                .collect(),
+            next_cursor: ResponseMetadata::into_next_cursor(self.response_metadata),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HISTORY_PAGE: &str = "{\"ok\":true,\"messages\":[{\"type\":\"message\",\"user\":\"U118BF6LQ\",\"text\":\"wat\",\"ts\":\"1687458843.576569\"}],\"has_more\":true,\"response_metadata\":{\"next_cursor\":\"bmV4dF90czoxNTEyMDg1ODYxMDAwNTQz\"}}";
+    const LAST_HISTORY_PAGE: &str = "{\"ok\":true,\"messages\":[],\"has_more\":false,\"response_metadata\":{\"next_cursor\":\"\"}}";
+
+    #[test]
+    fn should_convert_history_response_to_page_of_message_events() {
+        let response: ConversationHistoryResponse = serde_json::from_str(HISTORY_PAGE).unwrap();
+
+        let page = response.into_page("C2J0AD99Q");
+
+        assert_eq!(
+            page,
+            MessagePage {
+                messages: vec![MessageEvent {
+                    ts: Some("1687458843.576569".into()),
+                    text: Some("wat".to_string()),
+                    user: Some("U118BF6LQ".to_string()),
+                    blocks: None,
+                    channel: Some("C2J0AD99Q".to_string()),
+                    channel_type: None,
+                    ..MessageEvent::new("1687458843.576569".into())
+                }],
+                next_cursor: Some("bmV4dF90czoxNTEyMDg1ODYxMDAwNTQz".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn given_empty_cursor_should_be_last_page() {
+        let response: ConversationHistoryResponse =
+            serde_json::from_str(LAST_HISTORY_PAGE).unwrap();
+
+        let page = response.into_page("C2J0AD99Q");
+
+        assert_eq!(page.next_cursor, None);
+    }
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// A file that has been uploaded to Slack
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
+pub struct File {
+    pub id: String,
+    pub name: Option<String>,
+    pub title: Option<String>,
+    pub mimetype: Option<String>,
+    pub filetype: Option<String>,
+    pub size: Option<u64>,
+    pub url_private: Option<String>,
+    pub permalink: Option<String>,
+}
+
+/// Models a message received from the `files.getUploadURLExternal` endpoint.
+#[derive(Debug, Deserialize)]
+pub struct UploadUrlResponse {
+    /// The file contents should be posted here, the URL is only valid for a short while.
+    pub upload_url: String,
+    pub file_id: String,
+}
+
+/// Models a message received from the `files.completeUploadExternal` endpoint.
+#[derive(Debug, Deserialize)]
+pub struct CompleteUploadResponse {
+    #[serde(default)]
+    pub files: Vec<File>,
+}
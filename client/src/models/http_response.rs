@@ -11,8 +11,24 @@ pub struct HttpApiResponse {
 
 #[derive(Debug, Deserialize, PartialEq)]
 pub struct Message {
+    /// Not present in every response, e.g. `chat.update` omits it from the edited message
     #[serde(rename = "ts")]
-    pub id: MessageId,
+    pub id: Option<MessageId>,
     pub text: String,
     pub user: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_message_without_ts_should_have_no_id() {
+        let response: HttpApiResponse = serde_json::from_str(
+            "{\"ok\":true,\"channel\":\"C2J0AD99Q\",\"ts\":\"1687458843.576569\",\"message\":{\"text\":\"barfoo\",\"user\":\"U0G9QF9C6\"}}",
+        )
+        .unwrap();
+
+        assert_eq!(response.message.unwrap().id, None);
+    }
+}
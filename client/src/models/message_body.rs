@@ -1,4 +1,5 @@
 use crate::error::SlackClientError;
+use crate::models::blocks::validation::{validate_blocks, ValidationError, ValidationErrorKind};
 use crate::models::blocks::Block;
 
 #[derive(Debug, PartialEq)]
@@ -8,12 +9,11 @@ pub struct MessageBody {
 }
 
 impl MessageBody {
+    /// Fails with [`SlackClientError::InvalidMessage`] if Slack would reject the message
     pub fn new(blocks: Vec<Block>, text: Option<String>) -> Result<Self, SlackClientError> {
-        if blocks.is_empty() && text.is_none() {
-            Err(SlackClientError("Must have..".to_string()))
-        } else {
-            Ok(Self { blocks, text })
-        }
+        let body = Self { blocks, text };
+        body.validate().map_err(SlackClientError::InvalidMessage)?;
+        Ok(body)
     }
 
     pub fn from_text(text: &str) -> Self {
@@ -30,6 +30,17 @@ impl MessageBody {
     pub fn get_blocks(&self) -> &Vec<Block> {
         &self.blocks
     }
+
+    /// Check the message against Block Kit's limits, returning every problem found
+    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        if self.blocks.is_empty() && self.text.is_none() {
+            return Err(vec![ValidationError::new(
+                "message",
+                ValidationErrorKind::Empty,
+            )]);
+        }
+        validate_blocks(&self.blocks)
+    }
 }
 
 #[cfg(test)]
@@ -47,6 +58,31 @@ mod tests {
         assert!(result.is_err())
     }
 
+    #[test]
+    fn given_blocks_over_limits_constructor_should_return_where_they_are() {
+        let result = MessageBody::new(
+            crate::blocks! {
+                markdown("fine"),
+                markdown("{}", "x".repeat(3001)),
+            },
+            None,
+        );
+
+        match result {
+            Err(SlackClientError::InvalidMessage(errors)) => assert_eq!(
+                errors,
+                vec![ValidationError::new(
+                    "blocks[1].text.text",
+                    ValidationErrorKind::TooLong {
+                        max: 3000,
+                        actual: 3001
+                    }
+                )]
+            ),
+            _ => panic!("Expected an invalid message error"),
+        }
+    }
+
     #[test]
     fn given_text_but_no_blocks_constructor_should_succeed() {
         let result = MessageBody::new(vec![], Some("test".to_string()));
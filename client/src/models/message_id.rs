@@ -1,5 +1,5 @@
 use rvstruct::ValueStruct;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Deserialize, Serialize, ValueStruct, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Deserialize, Serialize, ValueStruct, Clone)]
 pub struct MessageId(pub String);
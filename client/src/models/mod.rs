@@ -1,7 +1,13 @@
 pub mod auth_test_response;
 pub mod blocks;
+pub mod channel;
+pub mod conversation_history;
+pub mod file;
 pub mod http_response;
 pub mod message_body;
 pub mod message_id;
+pub mod response_metadata;
+pub mod scheduled_message;
 pub mod socket_message;
-pub(crate) mod websocket_url_message;
+pub mod user;
+pub mod websocket_url_message;
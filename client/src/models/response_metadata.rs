@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+/// Pagination details included in responses from Slack's cursor-paginated endpoints.
+#[derive(Debug, Deserialize)]
+pub struct ResponseMetadata {
+    /// Slack sends an empty string rather than omitting the cursor on the last page.
+    pub next_cursor: Option<String>,
+}
+
+impl ResponseMetadata {
+    /// Get the cursor for the next page, if there is one.
+    pub fn into_next_cursor(metadata: Option<ResponseMetadata>) -> Option<String> {
+        metadata
+            .and_then(|metadata| metadata.next_cursor)
+            .filter(|cursor| !cursor.is_empty())
+    }
+}
@@ -0,0 +1,49 @@
+use crate::models::response_metadata::ResponseMetadata;
+use rvstruct::ValueStruct;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, ValueStruct, Clone)]
+pub struct ScheduledMessageId(pub String);
+
+/// A message that has been scheduled but not yet sent
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct ScheduledMessage {
+    pub id: ScheduledMessageId,
+    #[serde(rename = "channel_id")]
+    pub channel: String,
+    /// Unix timestamp of when the message will be sent
+    pub post_at: u64,
+    pub date_created: Option<u64>,
+    pub text: Option<String>,
+}
+
+/// Models a message received from the `chat.scheduleMessage` endpoint.
+#[derive(Debug, Deserialize)]
+pub struct ScheduleMessageResponse {
+    pub scheduled_message_id: ScheduledMessageId,
+}
+
+/// Models a message received from the `chat.scheduledMessages.list` endpoint.
+#[derive(Debug, Deserialize)]
+pub struct ScheduledMessageListResponse {
+    #[serde(default)]
+    pub scheduled_messages: Vec<ScheduledMessage>,
+    pub response_metadata: Option<ResponseMetadata>,
+}
+
+/// A page of messages waiting to be sent.
+#[derive(Debug, PartialEq)]
+pub struct ScheduledMessagePage {
+    pub scheduled_messages: Vec<ScheduledMessage>,
+    /// Pass this back to the client to fetch the next page, `None` if this is the last page.
+    pub next_cursor: Option<String>,
+}
+
+impl ScheduledMessageListResponse {
+    pub fn into_page(self) -> ScheduledMessagePage {
+        ScheduledMessagePage {
+            scheduled_messages: self.scheduled_messages,
+            next_cursor: ResponseMetadata::into_next_cursor(self.response_metadata),
+        }
+    }
+}
@@ -1,7 +1,12 @@
 use crate::models::blocks::Block;
+use crate::models::channel::Channel;
+use crate::models::file::File;
 use crate::models::message_id::MessageId;
-use serde::{Deserialize, Serialize};
+use crate::models::user::User;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_with::skip_serializing_none;
+use tracing::{debug, warn};
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(tag = "type")]
@@ -9,11 +14,14 @@ pub enum SocketMessage {
     #[serde(rename = "hello")]
     Hello {},
     #[serde(rename = "disconnect")]
-    Disconnect {},
+    Disconnect {
+        #[serde(default)]
+        reason: Option<DisconnectReason>,
+    },
     #[serde(rename = "events_api")]
     Event {
         envelope_id: String,
-        payload: Payload,
+        payload: Box<Payload>,
     },
     #[serde(rename = "interactive")]
     Interactive { envelope_id: String },
@@ -21,11 +29,62 @@ pub enum SocketMessage {
     SlashCommand { envelope_id: String },
 }
 
+/// Why Slack is closing a socket mode connection
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum DisconnectReason {
+    /// The connection will be closed in about 10 seconds, Slack rotates connections every few hours
+    Warning,
+    /// Slack wants us to reconnect now
+    RefreshRequested,
+    /// Socket mode has been turned off for the app, reconnecting won't work
+    LinkDisabled,
+    #[serde(other)]
+    Unknown,
+}
+
 // Ignores the type field, because it seems to always be `event_callback`
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(from = "RawPayload")]
 pub struct Payload {
+    #[serde(serialize_with = "serialize_event")]
     pub event: Event,
+    /// Unique across every delivery of the same event, including Slack's retries
+    pub event_id: Option<String>,
     pub authorizations: Vec<Authorization>,
+    /// The event exactly as Slack sent it, for fields that `event` doesn't model yet
+    #[serde(skip_serializing)]
+    pub raw_event: serde_json::Value,
+}
+
+/// Unknown events are written back out as they arrived
+fn serialize_event<S>(event: &Event, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match event {
+        Event::Unknown { raw, .. } => raw.serialize(serializer),
+        _ => event.serialize(serializer),
+    }
+}
+
+#[derive(Deserialize)]
+struct RawPayload {
+    event: serde_json::Value,
+    #[serde(default)]
+    event_id: Option<String>,
+    authorizations: Vec<Authorization>,
+}
+
+impl From<RawPayload> for Payload {
+    fn from(raw: RawPayload) -> Self {
+        Payload {
+            event: Event::from_raw(raw.event.clone()),
+            event_id: raw.event_id,
+            authorizations: raw.authorizations,
+            raw_event: raw.event,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -33,28 +92,53 @@ pub struct Authorization {
     pub user_id: String,
 }
 
+/// The types of the [`Event`] variants that carry the event's contents, and so can fail to parse.
+/// Keep in step with the variants listed before `Unimplemented event types`.
+const MODELLED_EVENT_TYPES: &[&str] = &[
+    "message",
+    "app_mention",
+    "emoji_changed",
+    "user_change",
+    "channel_created",
+    "channel_rename",
+    "channel_archive",
+    "channel_unarchive",
+    "channel_deleted",
+    "member_joined_channel",
+    "member_left_channel",
+    "team_join",
+    "reaction_added",
+    "reaction_removed",
+];
+
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum Event {
-    Message(MessageEvent),
+    Message(Box<MessageEvent>),
+    AppMention(AppMentionEvent),
     EmojiChanged(EmojiChangedEvent),
+    UserChange(UserChangeEvent),
+    ChannelCreated(ChannelCreatedEvent),
+    ChannelRename(ChannelRenameEvent),
+    ChannelArchive(ChannelArchiveEvent),
+    ChannelUnarchive(ChannelArchiveEvent),
+    ChannelDeleted(ChannelDeletedEvent),
+    MemberJoinedChannel(MemberJoinedChannelEvent),
+    MemberLeftChannel(MemberLeftChannelEvent),
+    TeamJoin(TeamJoinEvent),
+    ReactionAdded(ReactionEvent),
+    ReactionRemoved(ReactionEvent),
 
     //-- Unimplemented event types
     AppHomeOpened,
-    AppMention,
     AppRateLimited,
     AppRequested,
     AppUninstalled,
     CallRejected,
-    ChannelArchive,
-    ChannelCreated,
-    ChannelDeleted,
     ChannelHistoryChanged,
     ChannelIdChanged,
     ChannelLeft,
-    ChannelRename,
     ChannelShared,
-    ChannelUnarchive,
     ChannelUnshared,
     DndUpdated,
     DndUpdatedUser,
@@ -84,8 +168,6 @@ pub enum Event {
     ImOpen,
     InviteRequested,
     LinkShared,
-    MemberJoinedChannel,
-    MemberLeftChannel,
     // Of course there are 5 that randomly break the naming convention, thanks Slack
     #[serde(rename = "message.app_home")]
     MessageAppHome,
@@ -102,8 +184,6 @@ pub enum Event {
     MessageMetadataUpdated,
     PinAdded,
     PinRemoved,
-    ReactionAdded,
-    ReactionRemoved,
     ResourcesAdded,
     ResourcesRemoved,
     ScopeDenied,
@@ -122,11 +202,9 @@ pub enum Event {
     TeamAccessGranted,
     TeamAccessRevoked,
     TeamDomainChange,
-    TeamJoin,
     TeamRename,
     TokensRevoked,
     UrlVerification,
-    UserChange,
     UserHuddleChanged,
     UserProfileChanged,
     UserResourceDenied,
@@ -138,18 +216,49 @@ pub enum Event {
     WorkflowStepDeleted,
     WorkflowStepExecute,
     WorkflowUnpublished,
+
+    /// An event type, or shape of event, that isn't modelled yet.
+    ///
+    /// Only serialised as part of a [`Payload`], where it's written out as `raw`.
+    #[serde(skip)]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
 }
 
 impl Event {
+    /// Parse an event, falling back to [`Event::Unknown`] rather than failing if it's of a type or
+    /// shape we don't know
+    pub fn from_raw(raw: serde_json::Value) -> Event {
+        match Event::deserialize(&raw) {
+            Ok(event) => event,
+            Err(err) => {
+                let type_name = raw["type"].as_str().unwrap_or_default().to_string();
+                if Self::is_modelled(&type_name) {
+                    warn!("Could not parse `{type_name}` event, passing it on as unknown: {err}");
+                } else {
+                    debug!("Unsupported `{type_name}` event, passing it on as unknown: {err}");
+                }
+                Event::Unknown { type_name, raw }
+            }
+        }
+    }
+
+    /// Whether events of this type have a variant that parses their contents
+    fn is_modelled(type_name: &str) -> bool {
+        MODELLED_EVENT_TYPES.contains(&type_name)
+    }
+
     pub fn new_test_text_message(message: &str) -> Event {
-        Event::Message(MessageEvent {
-            id: MessageId("myMessageId".to_string()),
+        Event::Message(Box::new(MessageEvent {
             text: Some(message.to_string()),
             user: None,
             blocks: None,
             channel: None,
             channel_type: None,
-        })
+            ..MessageEvent::new(MessageId("myMessageId".to_string()))
+        }))
     }
 }
 
@@ -158,11 +267,125 @@ impl Event {
 pub struct MessageEvent {
     #[serde(rename = "event_ts")]
     pub id: MessageId,
+    pub ts: Option<MessageId>,
+    /// The `ts` of the thread's first message, if this message is in a thread
+    pub thread_ts: Option<MessageId>,
     pub text: Option<String>,
     pub user: Option<String>,
+    pub bot_id: Option<String>,
+    pub team: Option<String>,
     pub blocks: Option<Vec<Block>>,
+    pub files: Option<Vec<File>>,
+    pub edited: Option<Edited>,
     pub channel: Option<String>,
     pub channel_type: Option<String>,
+    /// `None` for a message someone has just posted
+    #[serde(flatten, deserialize_with = "deserialize_subtype")]
+    pub subtype: Option<MessageSubtype>,
+}
+
+impl MessageEvent {
+    /// A message with nothing but its ID, to fill in with struct update syntax
+    pub fn new(id: MessageId) -> Self {
+        MessageEvent {
+            id,
+            ts: None,
+            thread_ts: None,
+            text: None,
+            user: None,
+            bot_id: None,
+            team: None,
+            blocks: None,
+            files: None,
+            edited: None,
+            channel: None,
+            channel_type: None,
+            subtype: None,
+        }
+    }
+}
+
+/// Only a message without a `subtype` has none. Serde would treat any flattened `Option` that
+/// fails to parse as `None`, which would make a malformed edit look like a new message.
+fn deserialize_subtype<'de, D>(deserializer: D) -> Result<Option<MessageSubtype>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let fields = serde_json::Map::deserialize(deserializer)?;
+    if fields.get("subtype").is_none_or(serde_json::Value::is_null) {
+        return Ok(None);
+    }
+    MessageSubtype::deserialize(serde_json::Value::Object(fields))
+        .map(Some)
+        .map_err(D::Error::custom)
+}
+
+impl MessageEvent {
+    /// Whether someone has just posted this message, as opposed to it being edited, deleted, or
+    /// posted by Slack itself such as when someone joins a channel
+    pub fn is_new_message(&self) -> bool {
+        matches!(
+            self.subtype,
+            None | Some(MessageSubtype::BotMessage { .. })
+                | Some(MessageSubtype::ThreadBroadcast)
+                | Some(MessageSubtype::FileShare)
+        )
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(tag = "subtype", rename_all = "snake_case")]
+pub enum MessageSubtype {
+    /// Posted by an app or integration, which will have set `bot_id`
+    BotMessage {
+        #[serde(default)]
+        username: Option<String>,
+    },
+    /// A message was edited. The event's `channel` is the only other field set.
+    MessageChanged {
+        message: Box<MessageSnapshot>,
+        #[serde(default)]
+        previous_message: Option<Box<MessageSnapshot>>,
+    },
+    /// A message was deleted. The event's `channel` is the only other field set.
+    MessageDeleted {
+        deleted_ts: MessageId,
+        #[serde(default)]
+        previous_message: Option<Box<MessageSnapshot>>,
+    },
+    /// A reply in a thread that was also sent to the channel
+    ThreadBroadcast,
+    /// A message with files attached, which are in `files`
+    FileShare,
+    /// Someone joined the channel, `user` is who joined
+    ChannelJoin {
+        #[serde(default)]
+        inviter: Option<String>,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+/// A message nested inside a `message_changed` or `message_deleted` event, as it was after or
+/// before the change
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct MessageSnapshot {
+    pub ts: MessageId,
+    pub thread_ts: Option<MessageId>,
+    pub text: Option<String>,
+    pub user: Option<String>,
+    pub bot_id: Option<String>,
+    pub blocks: Option<Vec<Block>>,
+    pub files: Option<Vec<File>>,
+    pub edited: Option<Edited>,
+}
+
+/// Who last edited a message, and when
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
+pub struct Edited {
+    pub user: String,
+    pub ts: MessageId,
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -198,9 +421,107 @@ pub struct RenameEmojiEvent {
     pub new_name: String,
 }
 
-pub enum MaybeRelevantSocketMessage {
-    Relevant(serde_json::error::Result<SocketMessage>),
-    Irrelevant,
+/// Sent when a user's profile, name or other details change
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct UserChangeEvent {
+    pub user: User,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct ChannelCreatedEvent {
+    pub channel: Channel,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct ChannelRenameEvent {
+    pub channel: Channel,
+}
+
+/// Sent when a channel is archived, or unarchived
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct ChannelArchiveEvent {
+    /// The channel's ID
+    pub channel: String,
+    /// Who archived or unarchived it
+    pub user: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct ChannelDeletedEvent {
+    /// The channel's ID
+    pub channel: String,
+}
+
+/// Sent when someone joins a channel the bot is in
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct MemberJoinedChannelEvent {
+    pub user: String,
+    pub channel: String,
+    /// `C` for public channels, `G` for private ones
+    pub channel_type: Option<String>,
+    pub team: Option<String>,
+    /// Who added them, `None` if they joined by themselves
+    pub inviter: Option<String>,
+}
+
+/// Sent when someone leaves a channel the bot is in
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct MemberLeftChannelEvent {
+    pub user: String,
+    pub channel: String,
+    /// `C` for public channels, `G` for private ones
+    pub channel_type: Option<String>,
+    pub team: Option<String>,
+}
+
+/// Sent when someone new joins the workspace
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct TeamJoinEvent {
+    pub user: User,
+}
+
+/// Sent when someone mentions the bot, whether or not it can read the channel's other messages.
+///
+/// If it can, the same message is also sent as an [`Event::Message`].
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct AppMentionEvent {
+    #[serde(rename = "event_ts")]
+    pub id: MessageId,
+    pub ts: MessageId,
+    pub thread_ts: Option<MessageId>,
+    pub text: String,
+    pub user: String,
+    pub team: Option<String>,
+    pub blocks: Option<Vec<Block>>,
+    pub channel: String,
+}
+
+/// Sent when someone adds or removes a reaction
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct ReactionEvent {
+    #[serde(rename = "event_ts")]
+    pub id: MessageId,
+    /// Who reacted
+    pub user: String,
+    /// The emoji's name, without colons
+    pub reaction: String,
+    pub item: ReactionItem,
+    /// Who posted the message or file that was reacted to
+    #[serde(default)]
+    pub item_user: Option<String>,
+}
+
+/// What was reacted to
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ReactionItem {
+    Message { channel: String, ts: MessageId },
+    File { file: String },
+    FileComment { file: String, file_comment: String },
 }
 
 #[cfg(test)]
@@ -214,6 +535,28 @@ mod tests {
     const FAKE_NEW_MESSAGE_EVENT: &str = "{ \"client_msg_id\": \"aa022dae-607c-4e24-b0e1-f96c08855f4f\", \"type\": \"message\", \"text\": \"wat\", \"user\": \"U118BF6LQ\", \"ts\": \"1687458843.576569\", \"blocks\": [ { \"type\": \"rich_text\", \"block_id\": \"ZrfB\", \"elements\": [ { \"type\": \"rich_text_section\", \"elements\": [ { \"type\": \"text\", \"text\": \"wat\" } ] } ] } ], \"team\": \"T0G5PM4NR\", \"channel\": \"DEAS25LNP\", \"event_ts\": \"1687458843.576569\", \"channel_type\": \"im\"}";
     const FAKE_NEW_EMOJI_EVENT: &str = "{ \"type\": \"emoji_changed\", \"subtype\": \"add\", \"name\": \"blobcat_knife\", \"value\": \"https://emoji.slack-edge.com/T0G5PM4NR/blobcat_knife/8ce3359f5936936a.png\", \"event_ts\": \"1687458875.040100\"}";
     const FAKE_REMOVED_EMOJI_EVENT: &str = "{	\"type\": \"emoji_changed\",	\"subtype\": \"remove\",	\"names\": [\"picard_facepalm\"],	\"event_ts\" : \"1361482916.000004\"}";
+    const FAKE_USER_CHANGE_EVENT: &str = "{\"type\":\"user_change\",\"user\":{\"id\":\"U118BF6LQ\",\"team_id\":\"T0G5PM4NR\",\"name\":\"sam\",\"deleted\":false,\"real_name\":\"Sam Smith\",\"profile\":{\"real_name\":\"Sam Smith\",\"display_name\":\"sammy\"},\"is_bot\":false},\"cache_ts\":1687458900,\"event_ts\":\"1687458900.000100\"}";
+    const FAKE_CHANNEL_CREATED_EVENT: &str = "{\"type\":\"channel_created\",\"channel\":{\"id\":\"C024BE91L\",\"name\":\"fun\",\"created\":1360782804,\"creator\":\"U024BE7LH\"},\"event_ts\":\"1360782804.000200\"}";
+    const FAKE_CHANNEL_RENAME_EVENT: &str = "{\"type\":\"channel_rename\",\"channel\":{\"id\":\"C02ELGNBH\",\"name\":\"new_name\",\"created\":1360782804},\"event_ts\":\"1360782805.000300\"}";
+    const FAKE_MESSAGE_CHANGED_EVENT: &str = "{\"type\":\"message\",\"subtype\":\"message_changed\",\"hidden\":true,\"channel\":\"C123ABC456\",\"ts\":\"1358878755.000001\",\"message\":{\"type\":\"message\",\"user\":\"U123ABC456\",\"text\":\"Hello, world!\",\"ts\":\"1355517523.000005\",\"edited\":{\"user\":\"U123ABC456\",\"ts\":\"1358878755.000001\"}},\"previous_message\":{\"type\":\"message\",\"user\":\"U123ABC456\",\"text\":\"Hello world\",\"ts\":\"1355517523.000005\"},\"event_ts\":\"1358878755.000001\"}";
+    const FAKE_MESSAGE_DELETED_EVENT: &str = "{\"type\":\"message\",\"subtype\":\"message_deleted\",\"hidden\":true,\"channel\":\"C123ABC456\",\"ts\":\"1358878755.000001\",\"deleted_ts\":\"1358878749.000002\",\"event_ts\":\"1358878755.000001\"}";
+    const FAKE_BOT_MESSAGE_EVENT: &str = "{\"type\":\"message\",\"subtype\":\"bot_message\",\"ts\":\"1358877455.000010\",\"text\":\"Pushing is the answer\",\"bot_id\":\"BB12033\",\"username\":\"github\",\"channel\":\"C123ABC456\",\"event_ts\":\"1358877455.000010\"}";
+    const FAKE_THREAD_BROADCAST_EVENT: &str = "{\"type\":\"message\",\"subtype\":\"thread_broadcast\",\"text\":\"Sure thing\",\"user\":\"U123ABC456\",\"ts\":\"1517414906.000889\",\"thread_ts\":\"1517414896.001003\",\"channel\":\"C123ABC456\",\"event_ts\":\"1517414906.000889\"}";
+    const FAKE_FILE_SHARE_EVENT: &str = "{\"type\":\"message\",\"subtype\":\"file_share\",\"text\":\"\",\"user\":\"U123ABC456\",\"ts\":\"1700000000.000100\",\"files\":[{\"id\":\"F0123ABC456\",\"name\":\"ferris.png\",\"mimetype\":\"image/png\"}],\"channel\":\"C123ABC456\",\"event_ts\":\"1700000000.000100\"}";
+    const FAKE_CHANNEL_JOIN_EVENT: &str = "{\"type\":\"message\",\"subtype\":\"channel_join\",\"text\":\"<@U123ABC456> has joined the channel\",\"user\":\"U123ABC456\",\"inviter\":\"U0G9QF9C6\",\"ts\":\"1700000000.000200\",\"channel\":\"C123ABC456\",\"event_ts\":\"1700000000.000200\"}";
+    const FAKE_UNKNOWN_SUBTYPE_EVENT: &str = "{\"type\":\"message\",\"subtype\":\"channel_topic\",\"text\":\"set the channel topic: Rust\",\"user\":\"U123ABC456\",\"topic\":\"Rust\",\"ts\":\"1700000000.000300\",\"channel\":\"C123ABC456\",\"event_ts\":\"1700000000.000300\"}";
+    const FAKE_UNKNOWN_EVENT_PAYLOAD: &str = "{\"type\":\"event_callback\",\"event_id\":\"Ev0FUTURE\",\"authorizations\":[{\"user_id\":\"U0FAKEB0T\"}],\"event\":{\"type\":\"brand_new_thing\",\"user\":\"U123ABC456\",\"event_ts\":\"1700000000.000400\"}}";
+    const FAKE_REACTION_ADDED_EVENT: &str = "{\"type\":\"reaction_added\",\"user\":\"U123ABC456\",\"reaction\":\"thumbsup\",\"item_user\":\"U222222222\",\"item\":{\"type\":\"message\",\"channel\":\"C123ABC456\",\"ts\":\"1360782400.498405\"},\"event_ts\":\"1360782804.083113\"}";
+    const FAKE_REACTION_REMOVED_EVENT: &str = "{\"type\":\"reaction_removed\",\"user\":\"U123ABC456\",\"reaction\":\"thumbsup\",\"item\":{\"type\":\"file\",\"file\":\"F123ABC456\"},\"event_ts\":\"1360782804.083113\"}";
+    const FAKE_APP_MENTION_EVENT: &str = "{\"type\":\"app_mention\",\"user\":\"U061F7AUR\",\"text\":\"<@U0LAN0Z89> is it everything a river should be?\",\"ts\":\"1515449522.000016\",\"channel\":\"C0LAN2Q65\",\"event_ts\":\"1515449522000016\"}";
+    const FAKE_CHANNEL_ARCHIVE_EVENT: &str = "{\"type\":\"channel_archive\",\"channel\":\"C024BE91L\",\"user\":\"U024BE7LH\",\"event_ts\":\"1360782804.000500\"}";
+    const FAKE_CHANNEL_UNARCHIVE_EVENT: &str = "{\"type\":\"channel_unarchive\",\"channel\":\"C024BE91L\",\"user\":\"U024BE7LH\",\"event_ts\":\"1360782804.000600\"}";
+    const FAKE_CHANNEL_DELETED_EVENT: &str = "{\"type\":\"channel_deleted\",\"channel\":\"C024BE91L\",\"event_ts\":\"1360782804.000700\"}";
+    const FAKE_MEMBER_JOINED_CHANNEL_EVENT: &str = "{\"type\":\"member_joined_channel\",\"user\":\"W06GH7XHN\",\"channel\":\"C0698JE0H\",\"channel_type\":\"C\",\"team\":\"T024BE7LD\",\"inviter\":\"U123456789\",\"event_ts\":\"1360782804.000800\"}";
+    const FAKE_MEMBER_LEFT_CHANNEL_EVENT: &str = "{\"type\":\"member_left_channel\",\"user\":\"W06GH7XHN\",\"channel\":\"C0698JE0H\",\"channel_type\":\"C\",\"team\":\"T024BE7LD\",\"event_ts\":\"1360782804.000900\"}";
+    const FAKE_TEAM_JOIN_EVENT: &str = "{\"type\":\"team_join\",\"user\":{\"id\":\"U0NEWB1E\",\"team_id\":\"T0G5PM4NR\",\"name\":\"newbie\",\"deleted\":false,\"real_name\":\"New Bie\",\"profile\":{\"real_name\":\"New Bie\",\"display_name\":\"\"},\"is_bot\":false},\"event_ts\":\"1360782804.001000\"}";
+    const FAKE_UNPARSEABLE_MESSAGE_CHANGED_EVENT: &str = "{\"type\":\"message\",\"subtype\":\"message_changed\",\"hidden\":true,\"channel\":\"C123ABC456\",\"ts\":\"1358878755.000001\",\"message\":{\"type\":\"message\",\"user\":\"U123ABC456\",\"text\":\"karma++\"},\"event_ts\":\"1358878755.000001\"}";
+    const FAKE_DISCONNECT_MESSAGE: &str = "{\"type\":\"disconnect\",\"reason\":\"refresh_requested\",\"debug_info\":{\"host\":\"applink-7\"}}";
     const FAKE_RENAMED_EMOJI_EVENT: &str = "{	\"type\": \"emoji_changed\",	\"subtype\": \"rename\",	\"old_name\": \"grin\",	\"new_name\": \"cheese-grin\",	\"value\": \"https://my.slack.com/emoji/picard_facepalm/db8e287430eaa459.gif\",	\"event_ts\" : \"1361482916.000004\"}";
 
     #[test]
@@ -234,6 +577,7 @@ mod tests {
                                 )])
                                 .build()
                         )])
+                        .block_id(Some("ZrfB".to_string()))
                         .build()
                 )]
             );
@@ -242,6 +586,193 @@ mod tests {
         }
     }
 
+    #[test]
+    fn given_no_subtype_should_parse_new_message() {
+        let result: Event = serde_json::from_str(FAKE_NEW_MESSAGE_EVENT).unwrap();
+
+        if let Event::Message(message) = result {
+            assert_eq!(message.subtype, None);
+            assert_eq!(message.ts, Some("1687458843.576569".into()));
+            assert_eq!(message.team, Some("T0G5PM4NR".to_string()));
+            assert!(message.is_new_message());
+        } else {
+            panic!("Wrong type of event")
+        }
+    }
+
+    #[test]
+    fn should_parse_message_changed_event() {
+        let result: Event = serde_json::from_str(FAKE_MESSAGE_CHANGED_EVENT).unwrap();
+
+        if let Event::Message(message) = result {
+            assert!(!message.is_new_message());
+            assert_eq!(message.channel, Some("C123ABC456".to_string()));
+            assert_eq!(
+                message.subtype,
+                Some(MessageSubtype::MessageChanged {
+                    message: Box::new(MessageSnapshot {
+                        ts: "1355517523.000005".into(),
+                        thread_ts: None,
+                        text: Some("Hello, world!".to_string()),
+                        user: Some("U123ABC456".to_string()),
+                        bot_id: None,
+                        blocks: None,
+                        files: None,
+                        edited: Some(Edited {
+                            user: "U123ABC456".to_string(),
+                            ts: "1358878755.000001".into(),
+                        }),
+                    }),
+                    previous_message: Some(Box::new(MessageSnapshot {
+                        ts: "1355517523.000005".into(),
+                        thread_ts: None,
+                        text: Some("Hello world".to_string()),
+                        user: Some("U123ABC456".to_string()),
+                        bot_id: None,
+                        blocks: None,
+                        files: None,
+                        edited: None,
+                    })),
+                })
+            );
+        } else {
+            panic!("Wrong type of event")
+        }
+    }
+
+    #[test]
+    fn given_message_changed_event_fails_to_parse_should_be_unknown() {
+        let raw: serde_json::Value =
+            serde_json::from_str(FAKE_UNPARSEABLE_MESSAGE_CHANGED_EVENT).unwrap();
+
+        let result = Event::from_raw(raw);
+
+        assert!(
+            matches!(&result, Event::Unknown { type_name, .. } if type_name == "message"),
+            "Expected unknown event, got {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn given_message_with_unknown_block_should_parse_rest_of_message() {
+        let result = serde_json::from_str::<Event>(
+            "{\"type\":\"message\",\"text\":\"karma++\",\"user\":\"U123ABC456\",\"ts\":\"1355517523.000005\",\"event_ts\":\"1355517523.000005\",\"blocks\":[{\"type\":\"brand_new_block\",\"block_id\":\"abc\"}]}",
+        )
+        .unwrap();
+
+        let Event::Message(message) = result else {
+            panic!("Expected a message, got {:?}", result);
+        };
+        assert_eq!(message.text, Some("karma++".to_string()));
+        assert!(matches!(
+            &message.blocks.unwrap()[..],
+            [Block::Unknown { type_name, .. }] if type_name == "brand_new_block"
+        ));
+    }
+
+    #[test]
+    fn given_message_deleted_event_without_deleted_ts_should_fail_to_parse() {
+        let result = serde_json::from_str::<Event>(
+            "{\"type\":\"message\",\"subtype\":\"message_deleted\",\"channel\":\"C123ABC456\",\"event_ts\":\"1358878755.000001\"}",
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_parse_message_deleted_event() {
+        let result: Event = serde_json::from_str(FAKE_MESSAGE_DELETED_EVENT).unwrap();
+
+        if let Event::Message(message) = result {
+            assert!(!message.is_new_message());
+            assert_eq!(
+                message.subtype,
+                Some(MessageSubtype::MessageDeleted {
+                    deleted_ts: "1358878749.000002".into(),
+                    previous_message: None,
+                })
+            );
+        } else {
+            panic!("Wrong type of event")
+        }
+    }
+
+    #[test]
+    fn should_parse_bot_message_event() {
+        let result: Event = serde_json::from_str(FAKE_BOT_MESSAGE_EVENT).unwrap();
+
+        if let Event::Message(message) = result {
+            assert!(message.is_new_message());
+            assert_eq!(message.bot_id, Some("BB12033".to_string()));
+            assert_eq!(
+                message.subtype,
+                Some(MessageSubtype::BotMessage {
+                    username: Some("github".to_string())
+                })
+            );
+        } else {
+            panic!("Wrong type of event")
+        }
+    }
+
+    #[test]
+    fn should_parse_thread_broadcast_event() {
+        let result: Event = serde_json::from_str(FAKE_THREAD_BROADCAST_EVENT).unwrap();
+
+        if let Event::Message(message) = result {
+            assert!(message.is_new_message());
+            assert_eq!(message.subtype, Some(MessageSubtype::ThreadBroadcast));
+            assert_eq!(message.thread_ts, Some("1517414896.001003".into()));
+        } else {
+            panic!("Wrong type of event")
+        }
+    }
+
+    #[test]
+    fn should_parse_file_share_event() {
+        let result: Event = serde_json::from_str(FAKE_FILE_SHARE_EVENT).unwrap();
+
+        if let Event::Message(message) = result {
+            assert!(message.is_new_message());
+            assert_eq!(message.subtype, Some(MessageSubtype::FileShare));
+            let files = message.files.unwrap();
+            assert_eq!(files[0].id, "F0123ABC456".to_string());
+            assert_eq!(files[0].name, Some("ferris.png".to_string()));
+        } else {
+            panic!("Wrong type of event")
+        }
+    }
+
+    #[test]
+    fn should_parse_channel_join_event() {
+        let result: Event = serde_json::from_str(FAKE_CHANNEL_JOIN_EVENT).unwrap();
+
+        if let Event::Message(message) = result {
+            assert!(!message.is_new_message());
+            assert_eq!(
+                message.subtype,
+                Some(MessageSubtype::ChannelJoin {
+                    inviter: Some("U0G9QF9C6".to_string())
+                })
+            );
+        } else {
+            panic!("Wrong type of event")
+        }
+    }
+
+    #[test]
+    fn given_unknown_subtype_should_not_be_new_message() {
+        let result: Event = serde_json::from_str(FAKE_UNKNOWN_SUBTYPE_EVENT).unwrap();
+
+        if let Event::Message(message) = result {
+            assert!(!message.is_new_message());
+            assert_eq!(message.subtype, Some(MessageSubtype::Unknown));
+        } else {
+            panic!("Wrong type of event")
+        }
+    }
+
     #[test]
     fn should_parse_new_emoji_event() {
         let result: Event = serde_json::from_str(FAKE_NEW_EMOJI_EVENT).unwrap();
@@ -276,4 +807,240 @@ mod tests {
             assert_eq!(result.new_name, "cheese-grin".to_string())
         }
     }
+
+    #[test]
+    fn should_parse_user_change_event() {
+        let result: Event = serde_json::from_str(FAKE_USER_CHANGE_EVENT).unwrap();
+
+        if let Event::UserChange(result) = result {
+            assert_eq!(result.user.id, "U118BF6LQ".to_string());
+            assert_eq!(result.user.display_name(), "sammy");
+        } else {
+            panic!("Wrong type of event")
+        }
+    }
+
+    #[test]
+    fn should_parse_channel_created_event() {
+        let result: Event = serde_json::from_str(FAKE_CHANNEL_CREATED_EVENT).unwrap();
+
+        if let Event::ChannelCreated(result) = result {
+            assert_eq!(result.channel.id, "C024BE91L".to_string());
+            assert_eq!(result.channel.name, Some("fun".to_string()));
+            assert_eq!(result.channel.creator, Some("U024BE7LH".to_string()));
+        } else {
+            panic!("Wrong type of event")
+        }
+    }
+
+    #[test]
+    fn should_parse_channel_rename_event() {
+        let result: Event = serde_json::from_str(FAKE_CHANNEL_RENAME_EVENT).unwrap();
+
+        if let Event::ChannelRename(result) = result {
+            assert_eq!(result.channel.id, "C02ELGNBH".to_string());
+            assert_eq!(result.channel.name, Some("new_name".to_string()));
+        } else {
+            panic!("Wrong type of event")
+        }
+    }
+
+    #[test]
+    fn should_parse_app_mention_event() {
+        let result: Event = serde_json::from_str(FAKE_APP_MENTION_EVENT).unwrap();
+
+        assert_eq!(
+            result,
+            Event::AppMention(AppMentionEvent {
+                id: "1515449522000016".into(),
+                ts: "1515449522.000016".into(),
+                thread_ts: None,
+                text: "<@U0LAN0Z89> is it everything a river should be?".to_string(),
+                user: "U061F7AUR".to_string(),
+                team: None,
+                blocks: None,
+                channel: "C0LAN2Q65".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn should_parse_reaction_added_event() {
+        let result: Event = serde_json::from_str(FAKE_REACTION_ADDED_EVENT).unwrap();
+
+        assert_eq!(
+            result,
+            Event::ReactionAdded(ReactionEvent {
+                id: "1360782804.083113".into(),
+                user: "U123ABC456".to_string(),
+                reaction: "thumbsup".to_string(),
+                item: ReactionItem::Message {
+                    channel: "C123ABC456".to_string(),
+                    ts: "1360782400.498405".into(),
+                },
+                item_user: Some("U222222222".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn should_parse_reaction_removed_event() {
+        let result: Event = serde_json::from_str(FAKE_REACTION_REMOVED_EVENT).unwrap();
+
+        if let Event::ReactionRemoved(result) = result {
+            assert_eq!(
+                result.item,
+                ReactionItem::File {
+                    file: "F123ABC456".to_string()
+                }
+            );
+            assert_eq!(result.item_user, None);
+        } else {
+            panic!("Wrong type of event")
+        }
+    }
+
+    #[test]
+    fn given_unknown_event_type_should_keep_raw_event() {
+        let result: Payload = serde_json::from_str(FAKE_UNKNOWN_EVENT_PAYLOAD).unwrap();
+
+        let raw = serde_json::json!({
+            "type": "brand_new_thing",
+            "user": "U123ABC456",
+            "event_ts": "1700000000.000400",
+        });
+        assert_eq!(
+            result.event,
+            Event::Unknown {
+                type_name: "brand_new_thing".to_string(),
+                raw: raw.clone(),
+            }
+        );
+        assert_eq!(result.event_id, Some("Ev0FUTURE".to_string()));
+        assert_eq!(result.raw_event, raw);
+    }
+
+    #[test]
+    fn should_serialise_unknown_event_as_it_arrived() {
+        let payload: Payload = serde_json::from_str(FAKE_UNKNOWN_EVENT_PAYLOAD).unwrap();
+
+        let serialised = serde_json::to_value(&payload).unwrap();
+
+        assert_eq!(serialised["event"], payload.raw_event);
+        assert_eq!(
+            serde_json::from_value::<Payload>(serialised).unwrap(),
+            payload
+        );
+    }
+
+    #[test]
+    fn should_only_treat_event_types_with_contents_as_modelled() {
+        assert!(Event::is_modelled("message"));
+        assert!(Event::is_modelled("reaction_removed"));
+        assert!(!Event::is_modelled("app_home_opened"));
+        assert!(!Event::is_modelled("brand_new_thing"));
+    }
+
+    #[test]
+    fn given_known_event_type_with_unexpected_shape_should_be_unknown() {
+        let raw = serde_json::json!({ "type": "channel_created", "channel": "C024BE91L" });
+
+        let result = Event::from_raw(raw.clone());
+
+        assert_eq!(
+            result,
+            Event::Unknown {
+                type_name: "channel_created".to_string(),
+                raw,
+            }
+        );
+    }
+
+    #[test]
+    fn should_keep_raw_event_alongside_typed_event() {
+        let raw: serde_json::Value = serde_json::from_str(FAKE_CHANNEL_RENAME_EVENT).unwrap();
+        let payload = serde_json::json!({ "event": raw, "authorizations": [] });
+
+        let result: Payload = serde_json::from_value(payload).unwrap();
+
+        assert!(matches!(result.event, Event::ChannelRename(_)));
+        assert_eq!(result.raw_event["event_ts"], "1360782805.000300");
+    }
+
+    #[test]
+    fn should_parse_channel_archive_events() {
+        let archive: Event = serde_json::from_str(FAKE_CHANNEL_ARCHIVE_EVENT).unwrap();
+        let unarchive: Event = serde_json::from_str(FAKE_CHANNEL_UNARCHIVE_EVENT).unwrap();
+
+        let expected = ChannelArchiveEvent {
+            channel: "C024BE91L".to_string(),
+            user: Some("U024BE7LH".to_string()),
+        };
+        assert_eq!(archive, Event::ChannelArchive(expected));
+        assert!(matches!(unarchive, Event::ChannelUnarchive(_)));
+    }
+
+    #[test]
+    fn should_parse_channel_deleted_event() {
+        let result: Event = serde_json::from_str(FAKE_CHANNEL_DELETED_EVENT).unwrap();
+
+        assert_eq!(
+            result,
+            Event::ChannelDeleted(ChannelDeletedEvent {
+                channel: "C024BE91L".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn should_parse_member_joined_channel_event() {
+        let result: Event = serde_json::from_str(FAKE_MEMBER_JOINED_CHANNEL_EVENT).unwrap();
+
+        assert_eq!(
+            result,
+            Event::MemberJoinedChannel(MemberJoinedChannelEvent {
+                user: "W06GH7XHN".to_string(),
+                channel: "C0698JE0H".to_string(),
+                channel_type: Some("C".to_string()),
+                team: Some("T024BE7LD".to_string()),
+                inviter: Some("U123456789".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn should_parse_member_left_channel_event() {
+        let result: Event = serde_json::from_str(FAKE_MEMBER_LEFT_CHANNEL_EVENT).unwrap();
+
+        if let Event::MemberLeftChannel(result) = result {
+            assert_eq!(result.user, "W06GH7XHN".to_string());
+            assert_eq!(result.channel, "C0698JE0H".to_string());
+        } else {
+            panic!("Wrong type of event")
+        }
+    }
+
+    #[test]
+    fn should_parse_team_join_event() {
+        let result: Event = serde_json::from_str(FAKE_TEAM_JOIN_EVENT).unwrap();
+
+        if let Event::TeamJoin(result) = result {
+            assert_eq!(result.user.id, "U0NEWB1E".to_string());
+            assert_eq!(result.user.display_name(), "newbie");
+        } else {
+            panic!("Wrong type of event")
+        }
+    }
+
+    #[test]
+    fn should_parse_disconnect_reason() {
+        let result: SocketMessage = serde_json::from_str(FAKE_DISCONNECT_MESSAGE).unwrap();
+
+        assert_eq!(
+            result,
+            SocketMessage::Disconnect {
+                reason: Some(DisconnectReason::RefreshRequested)
+            }
+        );
+    }
 }
@@ -0,0 +1,89 @@
+use crate::models::response_metadata::ResponseMetadata;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
+pub struct User {
+    pub id: String,
+    /// The username, not necessarily what is displayed to other users. See [`User::display_name`].
+    pub name: String,
+    pub real_name: Option<String>,
+    #[serde(default)]
+    pub deleted: bool,
+    #[serde(default)]
+    pub is_bot: bool,
+    pub profile: Option<UserProfile>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
+pub struct UserProfile {
+    pub display_name: Option<String>,
+    pub real_name: Option<String>,
+}
+
+impl User {
+    /// The name Slack shows for this user, falling back to their username if they have not
+    /// set a display name.
+    pub fn display_name(&self) -> &str {
+        self.profile
+            .as_ref()
+            .and_then(|profile| profile.display_name.as_deref())
+            .filter(|display_name| !display_name.is_empty())
+            .unwrap_or(&self.name)
+    }
+}
+
+/// Models a message received from the `users.info` endpoint.
+#[derive(Debug, Deserialize)]
+pub struct UserInfoResponse {
+    pub user: User,
+}
+
+/// Models a message received from the `users.list` endpoint.
+#[derive(Debug, Deserialize)]
+pub struct UserListResponse {
+    #[serde(default)]
+    pub members: Vec<User>,
+    pub response_metadata: Option<ResponseMetadata>,
+}
+
+/// A page of users in the workspace.
+#[derive(Debug, PartialEq)]
+pub struct UserPage {
+    pub users: Vec<User>,
+    /// Pass this back to the client to fetch the next page, `None` if this is the last page.
+    pub next_cursor: Option<String>,
+}
+
+impl UserListResponse {
+    pub fn into_page(self) -> UserPage {
+        UserPage {
+            users: self.members,
+            next_cursor: ResponseMetadata::into_next_cursor(self.response_metadata),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USER: &str = "{\"id\":\"U118BF6LQ\",\"team_id\":\"T0G5PM4NR\",\"name\":\"sam\",\"deleted\":false,\"real_name\":\"Sam Smith\",\"tz\":\"Europe/London\",\"profile\":{\"real_name\":\"Sam Smith\",\"display_name\":\"\",\"status_text\":\"\"},\"is_admin\":false,\"is_bot\":false}";
+
+    #[test]
+    fn given_no_display_name_should_fall_back_to_username() {
+        let user: User = serde_json::from_str(USER).unwrap();
+
+        assert_eq!(user.display_name(), "sam");
+    }
+
+    #[test]
+    fn given_display_name_should_use_display_name() {
+        let mut user: User = serde_json::from_str(USER).unwrap();
+        user.profile.as_mut().unwrap().display_name = Some("sammy".to_string());
+
+        assert_eq!(user.display_name(), "sammy");
+    }
+}
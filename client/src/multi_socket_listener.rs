@@ -0,0 +1,289 @@
+use crate::error::SlackClientError;
+use crate::models::socket_message::SocketMessage;
+use crate::recent_ids::RecentIds;
+use crate::socket_listener::{SocketModeConfig, SocketModeListener, TungsteniteSocketModeListener};
+use crate::SlackClient;
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+/// Slack won't open more than this many socket mode connections for one app
+pub const MAX_CONNECTIONS: usize = 10;
+/// How many envelope and event IDs to remember when looking for duplicates
+const RECENT_ID_CAPACITY: usize = 1000;
+
+/// Keeps several socket mode connections open and merges them into one stream of messages.
+///
+/// Slack spreads events across all of an app's connections, so while one connection is
+/// reconnecting the others keep receiving. The same envelope can arrive on more than one
+/// connection while Slack rotates them, and Slack retries events it thinks weren't delivered, so
+/// anything with an `envelope_id` or `event_id` we've already seen is skipped.
+///
+/// A connection that fails is dropped and the others carry on, an error is only returned once
+/// every connection has failed.
+pub struct MultiSocketModeListener {
+    receiver: mpsc::Receiver<Result<SocketMessage, SlackClientError>>,
+    tasks: Vec<JoinHandle<()>>,
+    recent_ids: RecentIds,
+    /// Why the most recent connection to fail did, to explain why we've stopped
+    last_error: Option<SlackClientError>,
+}
+
+#[async_trait]
+impl SocketModeListener for MultiSocketModeListener {
+    async fn next(&mut self) -> Result<SocketMessage, SlackClientError> {
+        loop {
+            let message = match self.receiver.recv().await {
+                Some(Ok(message)) => message,
+                Some(Err(err)) => {
+                    warn!(
+                        "A socket mode connection failed, carrying on with the others: {}",
+                        err
+                    );
+                    self.last_error = Some(err);
+                    continue;
+                }
+                None => {
+                    return Err(self.last_error.take().unwrap_or_else(|| {
+                        SlackClientError::Websocket(
+                            "All socket mode connections have closed".to_string(),
+                        )
+                    }))
+                }
+            };
+
+            let ids = match &message {
+                SocketMessage::Event {
+                    envelope_id,
+                    payload,
+                } => vec![Some(envelope_id), payload.event_id.as_ref()],
+                SocketMessage::Interactive { envelope_id }
+                | SocketMessage::SlashCommand { envelope_id } => vec![Some(envelope_id)],
+                SocketMessage::Hello { .. } | SocketMessage::Disconnect { .. } => vec![],
+            };
+            let ids: Vec<&String> = ids.into_iter().flatten().collect();
+            if ids.iter().any(|id| self.recent_ids.contains(id.as_str())) {
+                debug!("Skipping duplicate socket mode message {:?}", ids);
+                continue;
+            }
+            for id in ids {
+                self.recent_ids.insert(id.clone());
+            }
+
+            return Ok(message);
+        }
+    }
+}
+
+impl MultiSocketModeListener {
+    /// Open `connections` socket mode connections, at most [`MAX_CONNECTIONS`]
+    pub async fn new(
+        client: Arc<dyn SlackClient + Send + Sync>,
+        connections: usize,
+        config: SocketModeConfig,
+    ) -> Result<Self, SlackClientError> {
+        let connections = connections.clamp(1, MAX_CONNECTIONS);
+        info!("Opening {} socket mode connections", connections);
+        let mut listeners: Vec<Box<dyn SocketModeListener + Send>> = vec![];
+        for _ in 0..connections {
+            listeners.push(Box::new(
+                TungsteniteSocketModeListener::with_config(client.clone(), config.clone()).await?,
+            ));
+        }
+
+        Ok(Self::from_listeners(listeners))
+    }
+
+    /// Merge messages from already connected listeners
+    pub fn from_listeners(listeners: Vec<Box<dyn SocketModeListener + Send>>) -> Self {
+        let (sender, receiver) = mpsc::channel(64);
+        let tasks = listeners
+            .into_iter()
+            .map(|mut listener| {
+                let sender = sender.clone();
+                tokio::spawn(async move {
+                    loop {
+                        let result = listener.next().await;
+                        let is_err = result.is_err();
+                        if sender.send(result).await.is_err() || is_err {
+                            break;
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        MultiSocketModeListener {
+            receiver,
+            tasks,
+            recent_ids: RecentIds::with_capacity(RECENT_ID_CAPACITY),
+            last_error: None,
+        }
+    }
+}
+
+impl Drop for MultiSocketModeListener {
+    fn drop(&mut self) {
+        for task in &self.tasks {
+            task.abort();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::socket_message::{Event, Payload};
+    use std::collections::VecDeque;
+    use std::time::Duration;
+
+    /// Yields its messages, then an error if it has one, then nothing ever again
+    struct FakeListener {
+        messages: VecDeque<SocketMessage>,
+        error: Option<SlackClientError>,
+    }
+
+    impl FakeListener {
+        fn boxed(messages: Vec<SocketMessage>) -> Box<dyn SocketModeListener + Send> {
+            Box::new(FakeListener {
+                messages: messages.into(),
+                error: None,
+            })
+        }
+    }
+
+    #[async_trait]
+    impl SocketModeListener for FakeListener {
+        async fn next(&mut self) -> Result<SocketMessage, SlackClientError> {
+            if let Some(message) = self.messages.pop_front() {
+                return Ok(message);
+            }
+            match self.error.take() {
+                Some(error) => Err(error),
+                None => std::future::pending().await,
+            }
+        }
+    }
+
+    /// Yields whatever is sent to it, for sending messages after another connection has failed
+    struct ChannelListener {
+        receiver: mpsc::Receiver<SocketMessage>,
+    }
+
+    #[async_trait]
+    impl SocketModeListener for ChannelListener {
+        async fn next(&mut self) -> Result<SocketMessage, SlackClientError> {
+            match self.receiver.recv().await {
+                Some(message) => Ok(message),
+                None => std::future::pending().await,
+            }
+        }
+    }
+
+    fn event(envelope_id: &str, event_id: &str) -> SocketMessage {
+        SocketMessage::Event {
+            envelope_id: envelope_id.to_string(),
+            payload: Box::new(Payload {
+                event: Event::new_test_text_message("hello"),
+                event_id: Some(event_id.to_string()),
+                authorizations: vec![],
+                raw_event: serde_json::Value::Null,
+            }),
+        }
+    }
+
+    async fn assert_nothing_more(listener: &mut MultiSocketModeListener) {
+        let result = tokio::time::timeout(Duration::from_millis(50), listener.next()).await;
+        assert!(result.is_err(), "Expected no more messages: {:?}", result);
+    }
+
+    #[tokio::test]
+    async fn should_merge_messages_from_every_connection() {
+        let mut listener = MultiSocketModeListener::from_listeners(vec![
+            FakeListener::boxed(vec![event("envelope-1", "event-1")]),
+            FakeListener::boxed(vec![event("envelope-2", "event-2")]),
+        ]);
+
+        let mut envelopes = vec![];
+        for _ in 0..2 {
+            if let SocketMessage::Event { envelope_id, .. } = listener.next().await.unwrap() {
+                envelopes.push(envelope_id);
+            }
+        }
+        envelopes.sort();
+
+        assert_eq!(envelopes, vec!["envelope-1", "envelope-2"]);
+        assert_nothing_more(&mut listener).await;
+    }
+
+    #[tokio::test]
+    async fn given_same_envelope_on_two_connections_should_only_return_it_once() {
+        let mut listener = MultiSocketModeListener::from_listeners(vec![
+            FakeListener::boxed(vec![event("envelope-1", "event-1")]),
+            FakeListener::boxed(vec![event("envelope-1", "event-1")]),
+        ]);
+
+        assert_eq!(
+            listener.next().await.unwrap(),
+            event("envelope-1", "event-1")
+        );
+        assert_nothing_more(&mut listener).await;
+    }
+
+    #[tokio::test]
+    async fn given_retried_event_in_new_envelope_should_only_return_it_once() {
+        let mut listener =
+            MultiSocketModeListener::from_listeners(vec![FakeListener::boxed(vec![
+                event("envelope-1", "event-1"),
+                event("envelope-2", "event-1"),
+            ])]);
+
+        assert_eq!(
+            listener.next().await.unwrap(),
+            event("envelope-1", "event-1")
+        );
+        assert_nothing_more(&mut listener).await;
+    }
+
+    #[tokio::test]
+    async fn given_every_connection_failed_should_return_error() {
+        let mut listener = MultiSocketModeListener::from_listeners(vec![Box::new(FakeListener {
+            messages: VecDeque::new(),
+            error: Some(SlackClientError::Websocket("link disabled".to_string())),
+        })]);
+
+        assert!(matches!(
+            listener.next().await,
+            Err(SlackClientError::Websocket(_))
+        ));
+        assert!(matches!(
+            listener.next().await,
+            Err(SlackClientError::Websocket(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn given_one_connection_failed_should_keep_receiving_from_others() {
+        let (sender, receiver) = mpsc::channel(1);
+        let mut listener = MultiSocketModeListener::from_listeners(vec![
+            Box::new(FakeListener {
+                messages: VecDeque::new(),
+                error: Some(SlackClientError::Websocket("connection lost".to_string())),
+            }),
+            Box::new(ChannelListener { receiver }),
+        ]);
+
+        sender.send(event("envelope-1", "event-1")).await.unwrap();
+        assert_eq!(
+            listener.next().await.unwrap(),
+            event("envelope-1", "event-1")
+        );
+        sender.send(event("envelope-2", "event-2")).await.unwrap();
+        assert_eq!(
+            listener.next().await.unwrap(),
+            event("envelope-2", "event-2")
+        );
+    }
+}
@@ -1,19 +1,99 @@
-use governor::middleware::NoOpMiddleware;
-use governor::state::{InMemoryState, NotKeyed};
-use governor::{clock, Quota, RateLimiter};
+use governor::{DefaultKeyedRateLimiter, Quota, RateLimiter};
 use nonzero::nonzero;
-use reqwest::{Request, Response};
+use reqwest::header::{HeaderMap, CONTENT_TYPE, RETRY_AFTER};
+use reqwest::{Request, Response, StatusCode};
 use reqwest_middleware::{Middleware, Next};
+use std::collections::HashMap;
+use std::time::Duration;
 use task_local_extensions::Extensions;
+use tracing::warn;
 
+/// How many times to retry a request Slack has rate limited before giving up
+const MAX_RETRIES: u32 = 3;
+/// Longer waits than this are returned to the caller instead of blocking them
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(30);
+/// Slack should always send `Retry-After` with a 429, but just in case
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
+
+/// Slack's rate limit tiers, see https://api.slack.com/apis/rate-limits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tier {
+    Tier1,
+    Tier2,
+    Tier3,
+    Tier4,
+}
+
+impl Tier {
+    /// The tier Slack puts a Web API method in, e.g. `chat.update`
+    pub fn for_method(method: &str) -> Tier {
+        match method {
+            "apps.connections.open" => Tier::Tier1,
+            "conversations.list" | "users.list" => Tier::Tier2,
+            "chat.update"
+            | "chat.delete"
+            | "chat.scheduleMessage"
+            | "chat.scheduledMessages.list"
+            | "chat.deleteScheduledMessage"
+            | "conversations.history"
+            | "conversations.replies"
+            | "conversations.info"
+            | "reactions.add"
+            | "reactions.remove" => Tier::Tier3,
+            _ => Tier::Tier4,
+        }
+    }
+
+    fn quota(&self) -> Quota {
+        match self {
+            // Slack documents tier 1 as "1+ per minute" and tolerates small bursts, which we need
+            // to reconnect socket mode more than once a minute
+            Tier::Tier1 => Quota::per_minute(nonzero!(1u32)).allow_burst(nonzero!(5u32)),
+            Tier::Tier2 => Quota::per_minute(nonzero!(20u32)),
+            Tier::Tier3 => Quota::per_minute(nonzero!(50u32)),
+            Tier::Tier4 => Quota::per_minute(nonzero!(100u32)),
+        }
+    }
+}
+
+/// Keeps requests within Slack's rate limits, and retries them if Slack says we went over anyway.
+///
+/// Each method is limited according to its tier, and `chat.postMessage` is also limited to one
+/// message per second in each channel.
+///
+/// Channels are keyed on what the request names them as, with any `#` and case ignored. The
+/// middleware can't look channels up, so a channel named `#general` in one request and by its ID
+/// in another is limited as two channels. Slack still enforces its limit and we retry when it does.
 pub struct RateLimitingMiddleware {
-    limiter: RateLimiter<NotKeyed, InMemoryState, clock::DefaultClock, NoOpMiddleware>,
+    methods: HashMap<Tier, DefaultKeyedRateLimiter<String>>,
+    channels: DefaultKeyedRateLimiter<String>,
 }
 
 impl RateLimitingMiddleware {
     pub fn new() -> RateLimitingMiddleware {
         RateLimitingMiddleware {
-            limiter: RateLimiter::direct(Quota::per_second(nonzero!(8u32))),
+            methods: [Tier::Tier1, Tier::Tier2, Tier::Tier3, Tier::Tier4]
+                .into_iter()
+                .map(|tier| (tier, RateLimiter::keyed(tier.quota())))
+                .collect(),
+            channels: RateLimiter::keyed(Quota::per_second(nonzero!(1u32))),
+        }
+    }
+
+    async fn until_ready(&self, req: &Request) {
+        let Some(method) = req.url().path().strip_prefix("/api/") else {
+            // e.g. uploading file contents, which isn't a Web API method
+            return;
+        };
+        let method = method.to_string();
+        self.methods[&Tier::for_method(&method)]
+            .until_key_ready(&method)
+            .await;
+
+        if method == "chat.postMessage" {
+            if let Some(channel) = channel_of(req) {
+                self.channels.until_key_ready(&channel).await;
+            }
         }
     }
 }
@@ -32,7 +112,122 @@ impl Middleware for RateLimitingMiddleware {
         extensions: &mut Extensions,
         next: Next<'_>,
     ) -> reqwest_middleware::Result<Response> {
-        self.limiter.until_ready().await;
-        next.run(req, extensions).await
+        let mut attempts = 0;
+        loop {
+            self.until_ready(&req).await;
+            let retry = match req.try_clone() {
+                Some(retry) if attempts < MAX_RETRIES => retry,
+                _ => return next.run(req, extensions).await,
+            };
+
+            let response = next.clone().run(retry, extensions).await?;
+            if response.status() != StatusCode::TOO_MANY_REQUESTS {
+                return Ok(response);
+            }
+            let wait = retry_after(response.headers()).unwrap_or(DEFAULT_RETRY_AFTER);
+            if wait > MAX_RETRY_AFTER {
+                return Ok(response);
+            }
+
+            attempts += 1;
+            warn!(
+                "Rate limited calling {}, retrying in {:?} (attempt {} of {})",
+                req.url(),
+                wait,
+                attempts,
+                MAX_RETRIES
+            );
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// How long Slack has asked us to wait before trying again, from the `Retry-After` header
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse().ok())
+        .map(Duration::from_secs)
+}
+
+/// The channel a JSON or form encoded request is for, if it has one
+fn channel_of(req: &Request) -> Option<String> {
+    let body = req.body()?.as_bytes()?;
+    let is_form = req
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/x-www-form-urlencoded"));
+    let channel = if is_form {
+        url::form_urlencoded::parse(body)
+            .find(|(key, _)| key == "channel")
+            .map(|(_, value)| value.into_owned())?
+    } else {
+        let body: serde_json::Value = serde_json::from_slice(body).ok()?;
+        body.get("channel")?.as_str()?.to_string()
+    };
+    Some(normalise_channel(&channel))
+}
+
+/// `#bots`, `bots` and `Bots` are all the same channel
+fn normalise_channel(channel: &str) -> String {
+    channel.trim_start_matches('#').to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+    use reqwest::Method;
+
+    #[test]
+    fn should_put_methods_in_their_tiers() {
+        assert_eq!(Tier::for_method("apps.connections.open"), Tier::Tier1);
+        assert_eq!(Tier::for_method("users.list"), Tier::Tier2);
+        assert_eq!(Tier::for_method("reactions.add"), Tier::Tier3);
+        assert_eq!(Tier::for_method("chat.postMessage"), Tier::Tier4);
+    }
+
+    #[test]
+    fn should_read_channel_from_json_body() {
+        let mut req = Request::new(
+            Method::POST,
+            "https://slack.com/api/chat.postMessage".parse().unwrap(),
+        );
+        *req.body_mut() = Some("{\"channel\":\"#bots\",\"text\":\"foobar\"}".into());
+
+        assert_eq!(channel_of(&req), Some("bots".to_string()));
+    }
+
+    #[test]
+    fn should_read_channel_from_form_body() {
+        let mut req = Request::new(
+            Method::POST,
+            "https://slack.com/api/chat.postMessage".parse().unwrap(),
+        );
+        req.headers_mut().insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/x-www-form-urlencoded"),
+        );
+        *req.body_mut() = Some("channel=%23bots&text=foobar".into());
+
+        assert_eq!(channel_of(&req), Some("bots".to_string()));
+    }
+
+    #[test]
+    fn should_key_channel_names_the_same_with_or_without_hash() {
+        assert_eq!(normalise_channel("#bots"), normalise_channel("bots"));
+        assert_eq!(normalise_channel("#Bots"), normalise_channel("bots"));
+        assert_eq!(normalise_channel("C024BE91L"), "c024be91l");
+    }
+
+    #[test]
+    fn given_no_retry_after_header_should_be_none() {
+        assert_eq!(retry_after(&HeaderMap::new()), None);
+
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("nonsense"));
+        assert_eq!(retry_after(&headers), None);
     }
 }
@@ -0,0 +1,72 @@
+use std::borrow::Borrow;
+use std::collections::{HashSet, VecDeque};
+use std::hash::Hash;
+
+/// The most recent IDs seen, forgetting the oldest once full.
+///
+/// Used to spot things Slack sends more than once, such as retried events.
+pub struct RecentIds<T = String> {
+    capacity: usize,
+    order: VecDeque<T>,
+    seen: HashSet<T>,
+}
+
+impl<T: Eq + Hash + Clone> RecentIds<T> {
+    pub fn with_capacity(capacity: usize) -> Self {
+        RecentIds {
+            capacity,
+            order: VecDeque::with_capacity(capacity),
+            seen: HashSet::with_capacity(capacity),
+        }
+    }
+
+    pub fn contains<Q>(&self, id: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.seen.contains(id)
+    }
+
+    /// Remember `id`, returning whether it is new
+    pub fn insert(&mut self, id: T) -> bool {
+        if self.seen.contains(&id) {
+            return false;
+        }
+        if self.order.len() == self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+        self.seen.insert(id.clone());
+        self.order.push_back(id);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_only_insert_new_ids() {
+        let mut recent_ids = RecentIds::with_capacity(10);
+
+        assert!(recent_ids.insert("Ev01".to_string()));
+        assert!(!recent_ids.insert("Ev01".to_string()));
+        assert!(recent_ids.contains("Ev01"));
+        assert!(!recent_ids.contains("Ev02"));
+    }
+
+    #[test]
+    fn should_forget_oldest_ids_once_full() {
+        let mut recent_ids = RecentIds::with_capacity(10);
+        for i in 0..=10 {
+            recent_ids.insert(i.to_string());
+        }
+
+        assert!(!recent_ids.contains("0"));
+        assert!(recent_ids.contains("1"));
+        assert!(recent_ids.contains("10"));
+    }
+}
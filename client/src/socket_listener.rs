@@ -1,140 +1,327 @@
 use crate::error::SlackClientError;
-use crate::models::socket_message::{MaybeRelevantSocketMessage, SocketMessage};
-use crate::socket_listener::MaybeRelevantSocketMessage::{Irrelevant, Relevant};
+use crate::models::socket_message::{DisconnectReason, SocketMessage};
 use crate::SlackClient;
-use async_timer::oneshot::Timer;
-use async_timer::Oneshot;
 use async_trait::async_trait;
+use builder_pattern::Builder;
 use futures_util::{SinkExt, StreamExt};
-
+use rand::Rng;
 use serde_json::json;
 use std::sync::Arc;
-use std::time;
+use std::time::Duration;
 use tokio::net::TcpStream;
+use tokio::time::{sleep, sleep_until, Instant, Interval, MissedTickBehavior};
 use tokio_tungstenite::tungstenite::Message;
 use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
 use tracing::{error, info, warn};
 
+type SocketStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
 #[async_trait]
 pub trait SocketModeListener {
-    async fn next(&mut self) -> serde_json::error::Result<SocketMessage>;
+    async fn next(&mut self) -> Result<SocketMessage, SlackClientError>;
 }
 
+/// How the socket mode connection is kept alive, and how hard to try to reconnect when it isn't.
+#[derive(Debug, Clone, Builder)]
+pub struct SocketModeConfig {
+    /// Reconnect if nothing arrives from Slack for this long. Slack pings every few seconds, so
+    /// silence means the connection has died without being closed.
+    #[default(Duration::from_secs(15))]
+    pub idle_timeout: Duration,
+    /// Also ping Slack this often, `None` to rely on Slack's pings
+    #[default(None)]
+    pub ping_interval: Option<Duration>,
+    /// How long to wait before the first attempt to reconnect, doubling after each failure
+    #[default(Duration::from_secs(1))]
+    pub initial_backoff: Duration,
+    #[default(Duration::from_secs(60))]
+    pub max_backoff: Duration,
+    /// Give up after this many failed attempts to reconnect in a row, `None` to never give up
+    #[default(None)]
+    pub max_reconnect_attempts: Option<u32>,
+}
+
+impl Default for SocketModeConfig {
+    fn default() -> Self {
+        SocketModeConfig::new().build()
+    }
+}
+
+impl SocketModeConfig {
+    /// How long to wait after `attempt` failed attempts to reconnect.
+    ///
+    /// Somewhere between half and all of the exponential backoff, so that many clients
+    /// disconnected at once don't all reconnect at once.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_backoff);
+        backoff.mul_f64(rand::thread_rng().gen_range(0.5..=1.0))
+    }
+}
+
+/// Listens for socket mode messages, acknowledging them and reconnecting whenever Slack closes
+/// the connection, asks us to, or goes quiet.
 pub struct TungsteniteSocketModeListener {
     client: Arc<dyn SlackClient + Send + Sync>,
-    stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+    config: SocketModeConfig,
+    /// `None` when we need to reconnect
+    stream: Option<SocketStream>,
+    ping: Option<Interval>,
+    /// When anything last arrived from Slack, our own pings don't count
+    last_received: Instant,
+    link_disabled: bool,
 }
 
 #[async_trait]
 impl SocketModeListener for TungsteniteSocketModeListener {
-    async fn next(&mut self) -> serde_json::Result<SocketMessage> {
-        let parsed_message;
-
+    async fn next(&mut self) -> Result<SocketMessage, SlackClientError> {
         loop {
+            if self.link_disabled {
+                return Err(SlackClientError::Websocket(
+                    "Socket mode has been disabled for this app".to_string(),
+                ));
+            }
+            let Some(stream) = self.stream.as_mut() else {
+                self.reconnect().await?;
+                continue;
+            };
+            let idle_deadline = self.last_received + self.config.idle_timeout;
+
             tokio::select! {
-                message = self.try_next() => {
+                message = stream.next() => {
                     match message {
-                        Relevant(message) => {
-                            parsed_message = Some(message);
-                            break;
-                        },
-                        Irrelevant => { continue; }
-                    }
-                }
-                _timeout = Timer::new(time::Duration::from_secs(15)) => {
-                    info!("Timed out awaiting message from Slack API. Restarting Websockets connection.");
-                    let stream_result = Self::init_stream(self.client.clone()).await;
-                    match stream_result {
-                        Ok(stream) => {
-                            self.stream = stream;
-                            continue;
+                        Some(Ok(message)) => {
+                            self.last_received = Instant::now();
+                            if let Some(message) = self.handle(message).await {
+                                return Ok(message);
+                            }
+                        }
+                        Some(Err(err)) => {
+                            warn!("Error reading from socket mode connection, reconnecting: {}", err);
+                            self.stream = None;
                         }
-                        Err(err) => {
-                            panic!("Failed to re-establish Websocket connection after timeout: {:?}", err);
+                        None => {
+                            warn!("Socket mode connection closed, reconnecting");
+                            self.stream = None;
                         }
                     }
                 }
+                _ = tick(&mut self.ping) => {
+                    if let Err(err) = stream.send(Message::Ping(vec![])).await {
+                        warn!("Failed to ping Slack, reconnecting: {}", err);
+                        self.stream = None;
+                    }
+                }
+                _ = sleep_until(idle_deadline) => {
+                    info!("Timed out awaiting message from Slack API. Restarting Websockets connection.");
+                    self.stream = None;
+                }
             }
         }
-
-        parsed_message.unwrap()
     }
 }
 
 impl TungsteniteSocketModeListener {
     pub async fn new(client: Arc<dyn SlackClient + Send + Sync>) -> Result<Self, SlackClientError> {
+        Self::with_config(client, SocketModeConfig::default()).await
+    }
+
+    /// Connect to socket mode, failing straight away if the first connection can't be made
+    pub async fn with_config(
+        client: Arc<dyn SlackClient + Send + Sync>,
+        config: SocketModeConfig,
+    ) -> Result<Self, SlackClientError> {
+        let ping = config.ping_interval.map(|period| {
+            let mut interval = tokio::time::interval(period);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            interval
+        });
         Ok(TungsteniteSocketModeListener {
             client: client.clone(),
-            stream: Self::init_stream(client).await?,
+            config,
+            stream: Some(Self::init_stream(client).await?),
+            ping,
+            last_received: Instant::now(),
+            link_disabled: false,
         })
     }
 
     async fn init_stream(
         client: Arc<dyn SlackClient + Send + Sync>,
-    ) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, SlackClientError> {
+    ) -> Result<SocketStream, SlackClientError> {
         let url = client.get_websocket_url().await?;
         let (stream, _) = connect_async(url).await?;
         Ok(stream)
     }
 
-    async fn try_next(&mut self) -> MaybeRelevantSocketMessage {
-        let message = self.stream.next().await.unwrap().unwrap();
-
-        if message.is_ping() {
-            self.stream
-                .send(Message::Pong("Pong from slackbot".to_string().into_bytes()))
-                .await
-                .unwrap();
-            Irrelevant
-        } else if message.is_close() {
-            warn!("Close message received");
-            Irrelevant
-        } else if !message.is_text() {
-            error!(
-                "Received unexpected non-text message from WSS: {:?}",
-                message
-            );
-            Irrelevant
-        } else {
-            let text = message.into_text().unwrap();
-            info!("Received message {}", text);
-            let result = serde_json::from_str(&text);
-
-            match &result {
-                Ok(inner) => {
-                    match inner {
-                        SocketMessage::Event {
-                            envelope_id,
-                            payload: _,
-                        } => {
-                            self.send_ack(envelope_id).await;
-                        }
-                        SocketMessage::Interactive { envelope_id } => {
-                            self.send_ack(envelope_id).await;
-                        }
-                        SocketMessage::SlashCommand { envelope_id } => {
-                            self.send_ack(envelope_id).await;
+    async fn reconnect(&mut self) -> Result<(), SlackClientError> {
+        let mut attempt = 0;
+        loop {
+            match Self::init_stream(self.client.clone()).await {
+                Ok(stream) => {
+                    info!("Reconnected to socket mode");
+                    self.stream = Some(stream);
+                    self.last_received = Instant::now();
+                    return Ok(());
+                }
+                Err(err) => {
+                    attempt += 1;
+                    if let Some(max_attempts) = self.config.max_reconnect_attempts {
+                        if attempt >= max_attempts {
+                            error!(
+                                "Giving up reconnecting to socket mode after {} attempts",
+                                attempt
+                            );
+                            return Err(err);
                         }
+                    }
+                    let backoff = self.config.backoff(attempt - 1);
+                    warn!(
+                        "Failed to reconnect to socket mode (attempt {}), retrying in {:?}: {}",
+                        attempt, backoff, err
+                    );
+                    sleep(backoff).await;
+                }
+            }
+        }
+    }
 
-                        SocketMessage::Hello { .. } => { /* Does not need to be ACK'd*/ }
-                        SocketMessage::Disconnect { .. } => { /* Does not need to be ACK'd*/ }
+    /// Deal with a message from the connection, returning it if it's something the bot wants
+    async fn handle(&mut self, message: Message) -> Option<SocketMessage> {
+        match message {
+            Message::Ping(payload) => {
+                self.send(Message::Pong(payload)).await;
+                None
+            }
+            Message::Pong(_) | Message::Frame(_) => None,
+            Message::Close(frame) => {
+                warn!("Close message received: {:?}", frame);
+                None
+            }
+            Message::Binary(_) => {
+                error!(
+                    "Received unexpected non-text message from WSS: {:?}",
+                    message
+                );
+                None
+            }
+            Message::Text(text) => {
+                info!("Received message {}", text);
+                match serde_json::from_str(&text) {
+                    Ok(message) => {
+                        self.on_message(&message).await;
+                        Some(message)
+                    }
+                    Err(err) => {
+                        warn!("Could not parse previous message from Slack (socket mode), probably an unsupported type not yet implemented. Caused by: `{}`", err);
+                        None
                     }
-                    Relevant(result)
                 }
-                Err(err) => {
-                    warn!("Could not parse previous message from Slack (socket mode), probably an unsupported type not yet implemented. Caused by: `{}`", err);
-                    Irrelevant
+            }
+        }
+    }
+
+    async fn on_message(&mut self, message: &SocketMessage) {
+        match message {
+            SocketMessage::Event { envelope_id, .. }
+            | SocketMessage::Interactive { envelope_id }
+            | SocketMessage::SlashCommand { envelope_id } => {
+                self.send(Message::Text(
+                    json!({ "envelope_id": envelope_id }).to_string(),
+                ))
+                .await;
+            }
+            SocketMessage::Hello { .. } => { /* Does not need to be ACK'd*/ }
+            SocketMessage::Disconnect { reason } => match reason {
+                Some(DisconnectReason::LinkDisabled) => {
+                    error!("Slack has disabled socket mode for this app, not reconnecting");
+                    self.close().await;
+                    self.link_disabled = true;
+                }
+                Some(DisconnectReason::Warning | DisconnectReason::RefreshRequested) => {
+                    info!("Slack asked us to reconnect ({:?})", reason);
+                    self.hand_over().await;
                 }
+                _ => {
+                    info!("Slack asked us to reconnect ({:?})", reason);
+                    self.close().await;
+                }
+            },
+        }
+    }
+
+    /// Send a message, dropping the connection to be reopened if it fails
+    async fn send(&mut self, message: Message) {
+        if let Some(stream) = self.stream.as_mut() {
+            if let Err(err) = stream.send(message).await {
+                warn!(
+                    "Failed to send to socket mode connection, reconnecting: {}",
+                    err
+                );
+                self.stream = None;
             }
         }
     }
 
-    async fn send_ack(&mut self, envelope_id: &String) {
-        self.stream
-            .send(Message::Text(
-                json!({ "envelope_id": envelope_id }).to_string(),
-            ))
-            .await
-            .unwrap();
+    /// Open a new connection before closing the old one, so that there's no gap between them. If
+    /// the new one can't be opened, the old one is kept until Slack closes it.
+    async fn hand_over(&mut self) {
+        match Self::init_stream(self.client.clone()).await {
+            Ok(stream) => {
+                self.close().await;
+                info!("Moved to a new socket mode connection");
+                self.stream = Some(stream);
+                self.last_received = Instant::now();
+            }
+            Err(err) => warn!(
+                "Failed to open a new socket mode connection, keeping the old one for now: {}",
+                err
+            ),
+        }
+    }
+
+    async fn close(&mut self) {
+        if let Some(mut stream) = self.stream.take() {
+            if let Err(err) = stream.close(None).await {
+                warn!("Failed to close socket mode connection cleanly: {}", err);
+            }
+        }
+    }
+}
+
+/// Waits for the next tick, or forever if there's no interval
+async fn tick(interval: &mut Option<Interval>) {
+    match interval {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_double_backoff_up_to_max() {
+        let config = SocketModeConfig::new()
+            .initial_backoff(Duration::from_secs(1))
+            .max_backoff(Duration::from_secs(10))
+            .build();
+
+        for (attempt, expected) in [(0, 1), (1, 2), (2, 4), (3, 8), (4, 10), (40, 10)] {
+            let backoff = config.backoff(attempt);
+            let expected = Duration::from_secs(expected);
+            assert!(
+                backoff >= expected / 2 && backoff <= expected,
+                "Attempt {} waited {:?}, expected up to {:?}",
+                attempt,
+                backoff,
+                expected
+            );
+        }
     }
 }
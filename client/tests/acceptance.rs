@@ -5,9 +5,16 @@ use client::models::blocks::objects::text::{Text, TextBody};
 use client::models::blocks::section::SectionBlock;
 use client::models::blocks::Block;
 use client::models::message_body::MessageBody;
-use std::time::SystemTime;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 
+use client::api::SlackApiMethod;
+use client::error::SlackClientError;
+use client::history::{conversation_history, thread_replies};
+use client::models::socket_message::MessageEvent;
 use client::SlackClient;
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
 
 #[tokio::test]
 async fn should_send_messages_to_channels_and_threads() {
@@ -19,14 +26,226 @@ async fn should_send_messages_to_channels_and_threads() {
         .await;
     assert!(result.is_ok());
     let message = result.unwrap().message.unwrap();
+    let message_id = message.id.unwrap();
 
     let new_message = MessageBody::from_text(&format!("replying to {}", message.text)[..]);
     let result2 = client
-        .message_thread("#bots", &message.id, &new_message)
+        .message_thread("#bots", &message_id, &new_message)
         .await;
     assert!(result2.is_ok());
 }
 
+#[tokio::test]
+async fn should_send_ephemeral_message() {
+    let builder = TestClientBuilder::new("should_send_ephemeral_message");
+    let client = builder.new_client();
+
+    let result = client
+        .message_ephemeral("#bots", "U118BF6LQ", &MessageBody::from_text("psst"))
+        .await;
+
+    assert!(result.unwrap().ok);
+}
+
+#[derive(Serialize)]
+struct PinsAddRequest {
+    channel: String,
+    timestamp: String,
+}
+
+#[derive(Deserialize)]
+struct PinsAddResponse {
+    ok: bool,
+}
+
+impl SlackApiMethod for PinsAddRequest {
+    type Response = PinsAddResponse;
+    const NAME: &'static str = "pins.add";
+}
+
+#[tokio::test]
+async fn should_call_methods_the_client_does_not_wrap() {
+    let builder = TestClientBuilder::new("should_call_methods_the_client_does_not_wrap");
+    let client = builder.new_client();
+    let pin = || PinsAddRequest {
+        channel: "C2J0AD99Q".to_string(),
+        timestamp: "1792246929.113769".to_string(),
+    };
+
+    let result = client.call(pin()).await;
+    assert!(result.unwrap().ok);
+
+    let result = client.call(pin()).await;
+    assert_eq!(
+        result
+            .err()
+            .and_then(|err| err.api_error().map(str::to_string)),
+        Some("already_pinned".to_string())
+    );
+}
+
+#[tokio::test]
+async fn given_unknown_channel_should_return_api_error() {
+    let builder = TestClientBuilder::new("given_unknown_channel_should_return_api_error");
+    let client = builder.new_client();
+
+    let result = client
+        .message_channel("#does-not-exist", &MessageBody::from_text("foobar"))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(SlackClientError::Api { ref error, .. }) if error == "channel_not_found"
+    ));
+}
+
+#[tokio::test]
+async fn given_rate_limited_should_return_retry_after() {
+    let builder = TestClientBuilder::new("given_rate_limited_should_return_retry_after");
+    let client = builder.new_client();
+
+    let result = client
+        .message_channel("#bots", &MessageBody::from_text("foobar"))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(SlackClientError::RateLimited { retry_after: Some(retry_after) })
+            if retry_after == Duration::from_secs(120)
+    ));
+}
+
+#[tokio::test]
+async fn should_schedule_list_and_delete_scheduled_messages() {
+    let builder = TestClientBuilder::new("should_schedule_list_and_delete_scheduled_messages");
+    let client = builder.new_client();
+
+    let id = client
+        .schedule_message("C2J0AD99Q", 1792252800, &MessageBody::from_text("foobar"))
+        .await
+        .unwrap();
+    let page = client
+        .list_scheduled_messages(Some("C2J0AD99Q".to_string()), None)
+        .await
+        .unwrap();
+    assert_eq!(page.scheduled_messages.len(), 1);
+    assert_eq!(page.scheduled_messages[0].id, id);
+    assert_eq!(page.scheduled_messages[0].post_at, 1792252800);
+
+    let result = client.delete_scheduled_message("C2J0AD99Q", &id).await;
+    assert!(result.unwrap().ok);
+}
+
+#[tokio::test]
+async fn should_update_and_delete_messages() {
+    let builder = TestClientBuilder::new("should_update_and_delete_messages");
+    let client = builder.new_client();
+
+    let result = client
+        .message_channel("#bots", &MessageBody::from_text("foobar"))
+        .await;
+    let message_id = result.unwrap().message.unwrap().id.unwrap();
+
+    let result = client
+        .update_message("C2J0AD99Q", &message_id, &MessageBody::from_text("barfoo"))
+        .await;
+    assert_eq!(result.unwrap().message.unwrap().text, "barfoo");
+    let result = client.delete_message("C2J0AD99Q", &message_id).await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn should_add_and_remove_reactions() {
+    let builder = TestClientBuilder::new("should_add_and_remove_reactions");
+    let client = builder.new_client();
+
+    let result = client
+        .message_channel("#bots", &MessageBody::from_text("foobar"))
+        .await;
+    let message_id = result.unwrap().message.unwrap().id.unwrap();
+
+    let result = client
+        .add_reaction("C2J0AD99Q", &message_id, ":upboat:")
+        .await;
+    assert!(result.unwrap().ok);
+    let result = client
+        .remove_reaction("C2J0AD99Q", &message_id, "upboat")
+        .await;
+    assert!(result.unwrap().ok);
+}
+
+#[tokio::test]
+async fn should_read_every_page_of_conversation_history() {
+    let builder = TestClientBuilder::new("should_read_every_page_of_conversation_history");
+    let client = builder.new_client();
+
+    let messages: Vec<MessageEvent> = conversation_history(Arc::new(client), "C2J0AD99Q")
+        .map(|message| message.unwrap())
+        .collect()
+        .await;
+
+    let texts: Vec<String> = messages
+        .into_iter()
+        .map(|message| message.text.unwrap())
+        .collect();
+    assert_eq!(texts, vec!["third", "second", "first"]);
+}
+
+#[tokio::test]
+async fn should_read_thread_replies() {
+    let builder = TestClientBuilder::new("should_read_thread_replies");
+    let client = builder.new_client();
+
+    let messages: Vec<MessageEvent> = thread_replies(
+        Arc::new(client),
+        "C2J0AD99Q",
+        &"1792246929.113769".to_string().into(),
+    )
+    .map(|message| message.unwrap())
+    .collect()
+    .await;
+
+    assert_eq!(messages.len(), 2);
+    assert_eq!(messages[1].text, Some("replying to foobar".to_string()));
+}
+
+#[tokio::test]
+async fn should_look_up_users_and_channels() {
+    let builder = TestClientBuilder::new("should_look_up_users_and_channels");
+    let client = builder.new_client();
+
+    let user = client.get_user("U118BF6LQ").await.unwrap();
+    assert_eq!(user.display_name(), "sam");
+    let users = client.list_users(None).await.unwrap();
+    assert_eq!(users.users.len(), 2);
+    assert_eq!(users.next_cursor, None);
+
+    let channel = client.get_channel("C2J0AD99Q").await.unwrap();
+    assert_eq!(channel.name, Some("bots".to_string()));
+    let channels = client.list_channels(None).await.unwrap();
+    assert_eq!(channels.channels.len(), 2);
+    assert_eq!(channels.next_cursor, None);
+}
+
+#[tokio::test]
+async fn should_upload_file_to_thread() {
+    let builder = TestClientBuilder::new("should_upload_file_to_thread");
+    let client = builder.new_client();
+
+    let result = client
+        .upload_file(
+            "C2J0AD99Q",
+            Some("1792246929.113769".to_string().into()),
+            "karma.csv",
+            b"name,karma\nsam,3\n".to_vec(),
+        )
+        .await;
+
+    let file = result.unwrap();
+    assert_eq!(file.id, "F09A1B2C3D4");
+    assert_eq!(file.title, Some("karma.csv".to_string()));
+}
+
 #[tokio::test]
 async fn given_too_many_requests_should_throttle_to_avoid_rate_limit() {
     let builder =
@@ -34,15 +253,35 @@ async fn given_too_many_requests_should_throttle_to_avoid_rate_limit() {
     let client = builder.new_client();
 
     let before = SystemTime::now();
-    for _i in 0..9 {
-        client
-            .message_channel("#bots", &MessageBody::from_text("foobar"))
-            .await
-            .expect("Should succeed");
+    for _i in 0..4 {
+        for channel in ["#bots", "#general"] {
+            client
+                .message_channel(channel, &MessageBody::from_text("foobar"))
+                .await
+                .expect("Should succeed");
+        }
     }
 
+    // chat.postMessage is limited to one message per second per channel, and channels are
+    // limited separately
+    let duration = before.elapsed().unwrap().as_millis();
+    assert!(duration >= 3000, "Wrong duration: {}", duration);
+    assert!(duration < 5000, "Wrong duration: {}", duration);
+}
+
+#[tokio::test]
+async fn given_rate_limited_briefly_should_retry_after_waiting() {
+    let builder = TestClientBuilder::new("given_rate_limited_briefly_should_retry_after_waiting");
+    let client = builder.new_client();
+
+    let before = SystemTime::now();
+    let result = client
+        .message_channel("#bots", &MessageBody::from_text("foobar"))
+        .await;
+
+    assert!(result.unwrap().ok);
     let duration = before.elapsed().unwrap().as_millis();
-    assert!(duration >= 75, "Wrong duration: {}", duration);
+    assert!(duration >= 1000, "Wrong duration: {}", duration);
 }
 
 #[tokio::test]
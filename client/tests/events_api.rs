@@ -0,0 +1,132 @@
+use client::events_api_listener::EventsApiListener;
+use client::models::socket_message::{Event, SocketMessage};
+use client::socket_listener::SocketModeListener;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const SIGNING_SECRET: &str = "not-a-real-signing-secret";
+const FAKE_URL_VERIFICATION: &str = "{\"token\":\"Jhj5dZrVaK7ZwHHjRyZWjbDl\",\"challenge\":\"3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P\",\"type\":\"url_verification\"}";
+const FAKE_EVENT_CALLBACK: &str = "{\"token\":\"F4K3T0K3N\",\"team_id\":\"F4K3T34M1D\",\"api_app_id\":\"F4K34P1ID\",\"event\":{\"client_msg_id\":\"fake-client-msg-id\",\"type\":\"message\",\"text\":\"test\",\"user\":\"F4K3USER1D\",\"ts\":\"1686321337.206879\",\"team\":\"F4K3T34M1D\",\"channel\":\"F4K3CH4NN3L1D\",\"event_ts\":\"1686321337.206879\",\"channel_type\":\"im\"},\"type\":\"event_callback\",\"event_id\":\"F4K33V3NT1D\",\"event_time\":1686321337,\"authorizations\":[{\"enterprise_id\":null,\"team_id\":\"F4K3T34M1D\",\"user_id\":\"F4K3USER1D\",\"is_bot\":true,\"is_enterprise_install\":false}]}";
+
+async fn start_listener() -> (EventsApiListener, String) {
+    let listener = EventsApiListener::bind("127.0.0.1:0".parse().unwrap(), SIGNING_SECRET)
+        .await
+        .unwrap();
+    let url = format!("http://{}/slack/events", listener.local_addr());
+    (listener, url)
+}
+
+async fn post_signed(url: &str, body: &str, signing_secret: &str) -> reqwest::Response {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        .to_string();
+    let mut mac = Hmac::<Sha256>::new_from_slice(signing_secret.as_bytes()).unwrap();
+    mac.update(format!("v0:{}:{}", timestamp, body).as_bytes());
+    let signature = format!("v0={}", hex::encode(mac.finalize().into_bytes()));
+
+    reqwest::Client::new()
+        .post(url)
+        .header("X-Slack-Request-Timestamp", timestamp)
+        .header("X-Slack-Signature", signature)
+        .header("Content-Type", "application/json")
+        .body(body.to_string())
+        .send()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn should_answer_url_verification_challenge() {
+    let (_listener, url) = start_listener().await;
+
+    let response = post_signed(&url, FAKE_URL_VERIFICATION, SIGNING_SECRET).await;
+
+    assert_eq!(response.status(), 200);
+    assert_eq!(
+        response.text().await.unwrap(),
+        "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P"
+    );
+}
+
+#[tokio::test]
+async fn should_receive_events() {
+    let (mut listener, url) = start_listener().await;
+
+    let response = post_signed(&url, FAKE_EVENT_CALLBACK, SIGNING_SECRET).await;
+    assert_eq!(response.status(), 200);
+
+    let message = listener.next().await.unwrap();
+    if let SocketMessage::Event {
+        envelope_id,
+        payload,
+    } = message
+    {
+        assert_eq!(envelope_id, "F4K33V3NT1D");
+        assert_eq!(payload.event_id, Some("F4K33V3NT1D".to_string()));
+        assert!(
+            matches!(payload.event, Event::Message(message) if message.text == Some("test".to_string()))
+        );
+    } else {
+        panic!("Wrong type of message {:?}", message)
+    }
+}
+
+#[tokio::test]
+async fn given_slack_retries_event_should_receive_it_once() {
+    let (mut listener, url) = start_listener().await;
+    let second_event = FAKE_EVENT_CALLBACK.replace("F4K33V3NT1D", "S3C0ND3V3NT1D");
+
+    assert_eq!(
+        post_signed(&url, FAKE_EVENT_CALLBACK, SIGNING_SECRET)
+            .await
+            .status(),
+        200
+    );
+    assert_eq!(
+        post_signed(&url, FAKE_EVENT_CALLBACK, SIGNING_SECRET)
+            .await
+            .status(),
+        200
+    );
+    assert_eq!(
+        post_signed(&url, &second_event, SIGNING_SECRET)
+            .await
+            .status(),
+        200
+    );
+
+    let first = listener.next().await.unwrap();
+    assert!(
+        matches!(first, SocketMessage::Event { envelope_id, .. } if envelope_id == "F4K33V3NT1D")
+    );
+    let second = listener.next().await.unwrap();
+    assert!(
+        matches!(second, SocketMessage::Event { envelope_id, .. } if envelope_id == "S3C0ND3V3NT1D")
+    );
+}
+
+#[tokio::test]
+async fn given_wrong_signature_should_reject_request() {
+    let (_listener, url) = start_listener().await;
+
+    let response = post_signed(&url, FAKE_EVENT_CALLBACK, "some-other-secret").await;
+
+    assert_eq!(response.status(), 401);
+}
+
+#[tokio::test]
+async fn given_no_signature_should_reject_request() {
+    let (_listener, url) = start_listener().await;
+
+    let response = reqwest::Client::new()
+        .post(&url)
+        .body(FAKE_URL_VERIFICATION)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), 401);
+}
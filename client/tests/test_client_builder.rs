@@ -72,7 +72,8 @@ impl TestClientBuilder {
         );
         let path = Path::new(&path);
         if TEST_CONFIG.is_record_mode && path.exists() {
-            fs::remove_file(path).expect(&format!("Failed to delete old cassette {:?}", path)[..]);
+            fs::remove_file(path)
+                .unwrap_or_else(|_| panic!("Failed to delete old cassette {:?}", path));
         }
 
         let mut vcr_testing_middleware = VCRMiddleware::try_from(path.to_path_buf()).unwrap();
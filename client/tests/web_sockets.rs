@@ -6,28 +6,44 @@ use client::models::blocks::elements::BlockElement;
 use client::models::blocks::objects::text::TextBody;
 use client::models::blocks::text::RichTextBlock;
 use client::models::blocks::Block;
-use client::models::socket_message::{Authorization, Event, MessageEvent};
+use client::models::socket_message::{Authorization, DisconnectReason, Event, MessageEvent};
 use client::models::socket_message::{Payload, SocketMessage};
-use client::socket_listener::{SocketModeListener, TungsteniteSocketModeListener};
+use client::socket_listener::{
+    SocketModeConfig, SocketModeListener, TungsteniteSocketModeListener,
+};
 
 use futures_util::{SinkExt, StreamExt};
 use serial_test::serial;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
 use tokio_tungstenite::tungstenite::Message;
 use tokio_tungstenite::{accept_async, WebSocketStream};
 
 const FAKE_SLACK_TEXT_MESSAGE: &str = "{\"envelope_id\":\"fake-enve-lope-i-d\",\"payload\":{\"token\":\"F4K3T0K3N\",\"team_id\":\"F4K3T34M1D\",\"context_team_id\":\"F4K3T34M1D\",\"context_enterprise_id\":null,\"api_app_id\":\"F4K34P1ID\",\"event\":{\"client_msg_id\":\"fake-client-msg-id\",\"type\":\"message\",\"text\":\"test\",\"user\":\"F4K3USER1D\",\"ts\":\"1686321337.206879\",\"blocks\":[{\"type\":\"rich_text\",\"block_id\":\"\\/5p\",\"elements\":[{\"type\":\"rich_text_section\",\"elements\":[{\"type\":\"text\",\"text\":\"test\"}]}]}],\"team\":\"F4K3T34M1D\",\"channel\":\"F4K3CH4NN3L1D\",\"event_ts\":\"1686321337.206879\",\"channel_type\":\"im\"},\"type\":\"event_callback\",\"event_id\":\"F4K33V3NT1D\",\"event_time\":1686321337,\"authed_users\":[\"F4K3USER1D\"],\"authorizations\":[{\"enterprise_id\":null,\"team_id\":\"F4K3T34M1D\",\"user_id\":\"F4K3USER1D\",\"is_bot\":true,\"is_enterprise_install\":false}],\"is_ext_shared_channel\":false,\"event_context\":\"4-fake-event-context\"},\"type\":\"events_api\",\"accepts_response_payload\":false,\"retry_attempt\":0,\"retry_reason\":\"\"}";
 const FAKE_HELLO_MESSAGE: &str = "{\"type\":\"hello\",\"num_connections\":1,\"debug_info\":{\"host\":\"applink-2\",\"build_number\":30,\"approximate_connection_time\":18060},\"connection_info\":{\"app_id\":\"fake-app-id\"}}";
 
+const FAKE_DISCONNECT_MESSAGE: &str = "{\"type\":\"disconnect\",\"reason\":\"refresh_requested\",\"debug_info\":{\"host\":\"applink-2\"}}";
+const FAKE_WARNING_MESSAGE: &str =
+    "{\"type\":\"disconnect\",\"reason\":\"warning\",\"debug_info\":{\"host\":\"applink-2\"}}";
+
 async fn start_websocket_server() -> WebSocketStream<TcpStream> {
     let listener = TcpListener::bind("127.0.0.1:12345").await.unwrap();
+    accept_connection(&listener).await
+}
+
+async fn accept_connection(listener: &TcpListener) -> WebSocketStream<TcpStream> {
     let (connection, _) = listener.accept().await.expect("No connections to accept");
     let stream = accept_async(connection).await;
 
     stream.expect("Failed to handshake with connection")
 }
 
+fn is_event(message: SocketMessage) -> bool {
+    matches!(message, SocketMessage::Event { envelope_id, .. } if envelope_id == "fake-enve-lope-i-d")
+}
+
 #[tokio::test]
 #[serial]
 async fn should_initiate_socket_mode_connection() {
@@ -58,11 +74,12 @@ async fn should_initiate_socket_mode_connection() {
         result.unwrap(),
         SocketMessage::Event {
             envelope_id: String::from("fake-enve-lope-i-d"),
-            payload: Payload {
-                event: Event::Message(MessageEvent {
-                    id: "1686321337.206879".to_string().into(),
+            payload: Box::new(Payload {
+                event: Event::Message(Box::new(MessageEvent {
+                    ts: Some("1686321337.206879".to_string().into()),
                     text: Some("test".to_string()),
                     user: Some("F4K3USER1D".to_string()),
+                    team: Some("F4K3T34M1D".to_string()),
                     blocks: Some(vec![Block::RichText(
                         RichTextBlock::new()
                             .elements(vec![BlockElement::RichTextSection(
@@ -72,15 +89,21 @@ async fn should_initiate_socket_mode_connection() {
                                     )])
                                     .build()
                             )])
+                            .block_id(Some("/5p".to_string()))
                             .build()
                     )]),
                     channel: Some("F4K3CH4NN3L1D".to_string()),
                     channel_type: Some("im".to_string()),
-                }),
+                    ..MessageEvent::new("1686321337.206879".to_string().into())
+                })),
+                event_id: Some("F4K33V3NT1D".to_string()),
                 authorizations: vec![Authorization {
                     user_id: "F4K3USER1D".to_string(),
                 }],
-            }
+                raw_event: serde_json::from_str::<serde_json::Value>(FAKE_SLACK_TEXT_MESSAGE)
+                    .unwrap()["payload"]["event"]
+                    .clone(),
+            })
         }
     );
     handle.abort();
@@ -122,3 +145,177 @@ async fn should_send_acknowledgement_of_event_messages() {
     );
     handle.abort();
 }
+
+#[tokio::test]
+#[serial]
+async fn should_reconnect_when_slack_requests_refresh() {
+    let websocket_server = async move {
+        let listener = TcpListener::bind("127.0.0.1:12345").await.unwrap();
+        let mut first = accept_connection(&listener).await;
+        first
+            .send(Message::Text(String::from(FAKE_HELLO_MESSAGE)))
+            .await
+            .unwrap();
+        first
+            .send(Message::Text(String::from(FAKE_DISCONNECT_MESSAGE)))
+            .await
+            .unwrap();
+        let mut second = accept_connection(&listener).await;
+        second
+            .send(Message::Text(String::from(FAKE_HELLO_MESSAGE)))
+            .await
+            .unwrap();
+        second
+            .send(Message::Text(String::from(FAKE_SLACK_TEXT_MESSAGE)))
+            .await
+            .unwrap();
+        std::future::pending::<()>().await;
+    };
+    let handle = tokio::spawn(websocket_server);
+    let builder = TestClientBuilder::new("should_reconnect_when_slack_requests_refresh");
+    let client = builder.new_client();
+    let mut listener = TungsteniteSocketModeListener::new(Arc::new(client))
+        .await
+        .unwrap();
+
+    assert_eq!(listener.next().await.unwrap(), SocketMessage::Hello {});
+    assert_eq!(
+        listener.next().await.unwrap(),
+        SocketMessage::Disconnect {
+            reason: Some(DisconnectReason::RefreshRequested)
+        }
+    );
+    assert_eq!(listener.next().await.unwrap(), SocketMessage::Hello {});
+    assert!(is_event(listener.next().await.unwrap()));
+    handle.abort();
+}
+
+#[tokio::test]
+#[serial]
+async fn given_warning_should_open_new_connection_before_closing_old() {
+    let (order_sender, mut order) = mpsc::unbounded_channel();
+    let websocket_server = async move {
+        let listener = TcpListener::bind("127.0.0.1:12345").await.unwrap();
+        let mut first = accept_connection(&listener).await;
+        first
+            .send(Message::Text(String::from(FAKE_HELLO_MESSAGE)))
+            .await
+            .unwrap();
+        first
+            .send(Message::Text(String::from(FAKE_WARNING_MESSAGE)))
+            .await
+            .unwrap();
+        let first_closed = order_sender.clone();
+        tokio::spawn(async move {
+            while let Some(Ok(message)) = first.next().await {
+                if let Message::Close(_) = message {
+                    break;
+                }
+            }
+            first_closed.send("first closed").unwrap();
+        });
+
+        let (connection, _) = listener.accept().await.unwrap();
+        order_sender.send("second opened").unwrap();
+        let mut second = accept_async(connection).await.unwrap();
+        second
+            .send(Message::Text(String::from(FAKE_HELLO_MESSAGE)))
+            .await
+            .unwrap();
+        second
+            .send(Message::Text(String::from(FAKE_SLACK_TEXT_MESSAGE)))
+            .await
+            .unwrap();
+        std::future::pending::<()>().await;
+    };
+    let handle = tokio::spawn(websocket_server);
+    let builder =
+        TestClientBuilder::new("given_warning_should_open_new_connection_before_closing_old");
+    let client = builder.new_client();
+    let mut listener = TungsteniteSocketModeListener::new(Arc::new(client))
+        .await
+        .unwrap();
+
+    assert_eq!(listener.next().await.unwrap(), SocketMessage::Hello {});
+    assert_eq!(
+        listener.next().await.unwrap(),
+        SocketMessage::Disconnect {
+            reason: Some(DisconnectReason::Warning)
+        }
+    );
+    assert_eq!(listener.next().await.unwrap(), SocketMessage::Hello {});
+    assert!(is_event(listener.next().await.unwrap()));
+    assert_eq!(order.recv().await, Some("second opened"));
+    assert_eq!(order.recv().await, Some("first closed"));
+    handle.abort();
+}
+
+#[tokio::test]
+#[serial]
+async fn given_idle_connection_should_reconnect() {
+    let websocket_server = async move {
+        let listener = TcpListener::bind("127.0.0.1:12345").await.unwrap();
+        let mut first = accept_connection(&listener).await;
+        first
+            .send(Message::Text(String::from(FAKE_HELLO_MESSAGE)))
+            .await
+            .unwrap();
+        let mut second = accept_connection(&listener).await;
+        second
+            .send(Message::Text(String::from(FAKE_SLACK_TEXT_MESSAGE)))
+            .await
+            .unwrap();
+        std::future::pending::<()>().await;
+    };
+    let handle = tokio::spawn(websocket_server);
+    let builder = TestClientBuilder::new("given_idle_connection_should_reconnect");
+    let client = builder.new_client();
+    let config = SocketModeConfig::new()
+        .idle_timeout(Duration::from_millis(200))
+        .build();
+    let mut listener = TungsteniteSocketModeListener::with_config(Arc::new(client), config)
+        .await
+        .unwrap();
+
+    assert_eq!(listener.next().await.unwrap(), SocketMessage::Hello {});
+    assert!(is_event(listener.next().await.unwrap()));
+    handle.abort();
+}
+
+#[tokio::test]
+#[serial]
+async fn given_idle_connection_while_pinging_should_reconnect() {
+    let websocket_server = async move {
+        let listener = TcpListener::bind("127.0.0.1:12345").await.unwrap();
+        let mut first = accept_connection(&listener).await;
+        first
+            .send(Message::Text(String::from(FAKE_HELLO_MESSAGE)))
+            .await
+            .unwrap();
+        // Stop reading from the first connection, so the bot's pings are never answered
+        let mut second = accept_connection(&listener).await;
+        second
+            .send(Message::Text(String::from(FAKE_SLACK_TEXT_MESSAGE)))
+            .await
+            .unwrap();
+        drop(first);
+        std::future::pending::<()>().await;
+    };
+    let handle = tokio::spawn(websocket_server);
+    let builder = TestClientBuilder::new("given_idle_connection_while_pinging_should_reconnect");
+    let client = builder.new_client();
+    let config = SocketModeConfig::new()
+        .idle_timeout(Duration::from_millis(200))
+        .ping_interval(Some(Duration::from_millis(50)))
+        .build();
+    let mut listener = TungsteniteSocketModeListener::with_config(Arc::new(client), config)
+        .await
+        .unwrap();
+
+    assert_eq!(listener.next().await.unwrap(), SocketMessage::Hello {});
+    let next = tokio::time::timeout(Duration::from_secs(5), listener.next())
+        .await
+        .expect("Listener never reconnected");
+    assert!(is_event(next.unwrap()));
+    handle.abort();
+}
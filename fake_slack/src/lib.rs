@@ -0,0 +1,490 @@
+//! An in-process stand-in for Slack, for testing bots end to end without a real workspace.
+//!
+//! [`FakeSlack`] serves the Web API methods a bot needs to start and post messages, and a socket
+//! mode endpoint that tests push events through. Everything the bot sends back is recorded so
+//! tests can assert on it:
+//!
+//! ```no_run
+//! # async fn example() {
+//! use fake_slack::FakeSlack;
+//! use std::sync::Arc;
+//!
+//! let slack = FakeSlack::start().await;
+//! let bot = framework::SlackBot::from_client(Arc::new(slack.client()));
+//!
+//! let (result, _) = tokio::join!(bot.run(), async {
+//!     slack.send_message("C123", "U123", "hello").await;
+//!     let posted = slack.wait_for_posted_messages(1).await;
+//!     // Stops the bot
+//!     slack.disable_socket_mode().await;
+//! });
+//! result.unwrap();
+//! # }
+//! ```
+use client::models::socket_message::{
+    Authorization, DisconnectReason, Event, MessageEvent, Payload, SocketMessage,
+};
+use client::ReqwestSlackClient;
+use futures_util::{SinkExt, StreamExt};
+use hyper::header::CONTENT_TYPE;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Request, Response, Server, StatusCode};
+use serde_json::{json, Value};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio::time::sleep;
+use tokio_tungstenite::accept_async;
+use tokio_tungstenite::tungstenite::Message;
+use tracing::{debug, error, warn};
+use url::form_urlencoded;
+
+/// The bot's user ID, as returned from `auth.test`
+pub const BOT_USER_ID: &str = "U0FAKEB0T";
+/// The bot's name, as returned from `auth.test`
+pub const BOT_NAME: &str = "termibot";
+pub const TEAM_ID: &str = "T0FAKET34M";
+const FAKE_TOKEN: &str = "xoxn-not-a-real-token";
+
+/// How long the `wait_for_...` methods wait before failing the test
+const WAIT_TIMEOUT: Duration = Duration::from_secs(5);
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// A Web API method the bot called
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiCall {
+    pub method: String,
+    /// The request body, form encoded requests are converted to an object of strings
+    pub body: Value,
+}
+
+#[derive(Default)]
+struct State {
+    calls: Mutex<Vec<ApiCall>>,
+    connections: Mutex<Vec<mpsc::UnboundedSender<Message>>>,
+    acknowledged: Mutex<Vec<String>>,
+    next_id: AtomicUsize,
+}
+
+impl State {
+    fn next_id(&self) -> usize {
+        self.next_id.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    fn next_ts(&self) -> String {
+        format!("1700000000.{:06}", self.next_id())
+    }
+}
+
+/// A local HTTP and websocket server that behaves enough like Slack to run a bot against.
+///
+/// Both servers listen on random ports and stop when this is dropped.
+pub struct FakeSlack {
+    state: Arc<State>,
+    api_addr: SocketAddr,
+    servers: Vec<JoinHandle<()>>,
+}
+
+impl Drop for FakeSlack {
+    fn drop(&mut self) {
+        for server in &self.servers {
+            server.abort();
+        }
+    }
+}
+
+impl FakeSlack {
+    pub async fn start() -> FakeSlack {
+        let state = Arc::new(State::default());
+
+        let socket_listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("Failed to bind fake socket mode server");
+        let socket_addr = socket_listener
+            .local_addr()
+            .expect("Fake socket mode server has no address");
+        let socket_server = tokio::spawn(serve_socket_mode(socket_listener, state.clone()));
+
+        let api_state = state.clone();
+        let make_service = make_service_fn(move |_| {
+            let state = api_state.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    handle_api_request(request, state.clone(), socket_addr)
+                }))
+            }
+        });
+        let server = Server::bind(&SocketAddr::from(([127, 0, 0, 1], 0))).serve(make_service);
+        let api_addr = server.local_addr();
+        let api_server = tokio::spawn(async move {
+            if let Err(err) = server.await {
+                error!("Fake Slack API server failed: {}", err);
+            }
+        });
+
+        FakeSlack {
+            state,
+            api_addr,
+            servers: vec![socket_server, api_server],
+        }
+    }
+
+    /// The URL to give [`ReqwestSlackClient::with_base_url`]
+    pub fn base_url(&self) -> String {
+        format!("http://{}/api/", self.api_addr)
+    }
+
+    /// A client that talks to this fake instead of Slack
+    pub fn client(&self) -> ReqwestSlackClient {
+        ReqwestSlackClient::new(FAKE_TOKEN, FAKE_TOKEN).with_base_url(&self.base_url())
+    }
+
+    /// Send a message to the bot over socket mode, waiting for it to connect first
+    pub async fn send(&self, message: &SocketMessage) {
+        let json = serde_json::to_string(message).expect("Failed to serialise socket message");
+        self.send_json(&json).await;
+    }
+
+    /// Send raw JSON to the bot over socket mode, for messages the client can't model
+    pub async fn send_json(&self, json: &str) {
+        let connection = wait_for("the bot to connect to socket mode", || {
+            self.state
+                .connections
+                .lock()
+                .unwrap()
+                .iter()
+                .rev()
+                .find(|connection| !connection.is_closed())
+                .cloned()
+        })
+        .await;
+        debug!("Sending to the bot: {}", json);
+        if connection.send(Message::Text(json.to_string())).is_err() {
+            panic!("The bot disconnected before the message could be sent");
+        }
+    }
+
+    /// Send the bot an event, returning the envelope ID the bot should acknowledge
+    pub async fn send_event(&self, event: Event) -> String {
+        let id = self.state.next_id();
+        let envelope_id = format!("fake-envelope-{id}");
+        self.send(&SocketMessage::Event {
+            envelope_id: envelope_id.clone(),
+            payload: Box::new(Payload {
+                event,
+                event_id: Some(format!("Ev0FAKE{id}")),
+                authorizations: vec![Authorization {
+                    user_id: BOT_USER_ID.to_string(),
+                }],
+                raw_event: serde_json::Value::Null,
+            }),
+        })
+        .await;
+        envelope_id
+    }
+
+    /// Send the bot a message event, as if `user` had posted `text` in `channel`
+    pub async fn send_message(&self, channel: &str, user: &str, text: &str) -> String {
+        let ts = self.state.next_ts();
+        self.send_event(Event::Message(Box::new(MessageEvent {
+            ts: Some(ts.clone().into()),
+            text: Some(text.to_string()),
+            user: Some(user.to_string()),
+            team: Some(TEAM_ID.to_string()),
+            blocks: None,
+            channel: Some(channel.to_string()),
+            channel_type: Some("channel".to_string()),
+            ..MessageEvent::new(ts.into())
+        })))
+        .await
+    }
+
+    /// Tell the bot socket mode has been turned off, which stops `SlackBot::run`
+    pub async fn disable_socket_mode(&self) {
+        self.send(&SocketMessage::Disconnect {
+            reason: Some(DisconnectReason::LinkDisabled),
+        })
+        .await;
+    }
+
+    /// Every Web API call the bot has made so far, oldest first
+    pub fn calls(&self) -> Vec<ApiCall> {
+        self.state.calls.lock().unwrap().clone()
+    }
+
+    /// The bodies of every call to `method` so far, oldest first
+    pub fn calls_to(&self, method: &str) -> Vec<Value> {
+        self.calls()
+            .into_iter()
+            .filter(|call| call.method == method)
+            .map(|call| call.body)
+            .collect()
+    }
+
+    /// Wait until the bot has called `method` at least `count` times, returning the bodies.
+    ///
+    /// Panics if that doesn't happen within a few seconds.
+    pub async fn wait_for_calls(&self, method: &str, count: usize) -> Vec<Value> {
+        wait_for(&format!("{count} call(s) to {method}"), || {
+            let calls = self.calls_to(method);
+            (calls.len() >= count).then_some(calls)
+        })
+        .await
+    }
+
+    /// Wait until the bot has posted at least `count` messages with `chat.postMessage`
+    pub async fn wait_for_posted_messages(&self, count: usize) -> Vec<Value> {
+        self.wait_for_calls("chat.postMessage", count).await
+    }
+
+    /// The envelope IDs the bot has acknowledged so far
+    pub fn acknowledged(&self) -> Vec<String> {
+        self.state.acknowledged.lock().unwrap().clone()
+    }
+
+    /// Wait until the bot has acknowledged `envelope_id`, which it does before acting on the event
+    pub async fn wait_for_acknowledgement(&self, envelope_id: &str) {
+        wait_for(&format!("the bot to acknowledge {envelope_id}"), || {
+            self.acknowledged()
+                .iter()
+                .any(|acknowledged| acknowledged == envelope_id)
+                .then_some(())
+        })
+        .await
+    }
+}
+
+async fn wait_for<T>(what: &str, mut check: impl FnMut() -> Option<T>) -> T {
+    let start = Instant::now();
+    loop {
+        if let Some(result) = check() {
+            return result;
+        }
+        if start.elapsed() > WAIT_TIMEOUT {
+            panic!("Timed out waiting for {what}");
+        }
+        sleep(POLL_INTERVAL).await;
+    }
+}
+
+async fn handle_api_request(
+    request: Request<Body>,
+    state: Arc<State>,
+    socket_addr: SocketAddr,
+) -> Result<Response<Body>, Infallible> {
+    let Some(method) = request.uri().path().strip_prefix("/api/").map(String::from) else {
+        return Ok(Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .body(Body::empty())
+            .unwrap());
+    };
+    let is_form = request
+        .headers()
+        .get(CONTENT_TYPE)
+        .is_some_and(|content_type| {
+            content_type
+                .as_bytes()
+                .starts_with(b"application/x-www-form-urlencoded")
+        });
+    let body = match hyper::body::to_bytes(request.into_body()).await {
+        Ok(body) => body,
+        Err(err) => {
+            warn!("Failed to read request to the fake Slack API: {}", err);
+            return Ok(Response::builder()
+                .status(StatusCode::BAD_REQUEST)
+                .body(Body::empty())
+                .unwrap());
+        }
+    };
+    let body = if is_form {
+        Value::Object(
+            form_urlencoded::parse(&body)
+                .map(|(key, value)| (key.into_owned(), Value::String(value.into_owned())))
+                .collect(),
+        )
+    } else {
+        serde_json::from_slice(&body).unwrap_or(Value::Null)
+    };
+    debug!("Fake Slack API called {} with {}", method, body);
+
+    let response = respond(&method, &body, &state, socket_addr);
+    state.calls.lock().unwrap().push(ApiCall { method, body });
+    Ok(Response::builder()
+        .header(CONTENT_TYPE, "application/json; charset=utf-8")
+        .body(Body::from(response.to_string()))
+        .unwrap())
+}
+
+/// Canned responses, just enough for the client to deserialise. Other methods get `"ok": true`.
+fn respond(method: &str, body: &Value, state: &State, socket_addr: SocketAddr) -> Value {
+    match method {
+        "auth.test" => json!({
+            "ok": true,
+            "url": "https://fake-slack.slack.com/",
+            "team": "Fake Slack",
+            "user": BOT_NAME,
+            "team_id": TEAM_ID,
+            "user_id": BOT_USER_ID,
+            "is_enterprise_install": false,
+        }),
+        "apps.connections.open" => json!({
+            "ok": true,
+            "url": format!("ws://{socket_addr}/"),
+        }),
+        "chat.postMessage" => {
+            let ts = state.next_ts();
+            json!({
+                "ok": true,
+                "channel": body["channel"],
+                "ts": ts,
+                "message": {
+                    "ts": ts,
+                    "text": body["text"].as_str().unwrap_or_default(),
+                    "user": BOT_USER_ID,
+                },
+            })
+        }
+        _ => json!({ "ok": true }),
+    }
+}
+
+async fn serve_socket_mode(listener: TcpListener, state: Arc<State>) {
+    loop {
+        match listener.accept().await {
+            Ok((connection, _)) => {
+                tokio::spawn(handle_socket_connection(connection, state.clone()));
+            }
+            Err(err) => error!("Fake socket mode server failed to accept: {}", err),
+        }
+    }
+}
+
+async fn handle_socket_connection(connection: TcpStream, state: Arc<State>) {
+    let stream = match accept_async(connection).await {
+        Ok(stream) => stream,
+        Err(err) => {
+            warn!("Fake socket mode handshake failed: {}", err);
+            return;
+        }
+    };
+    let (mut sink, mut stream) = stream.split();
+    let (sender, mut outgoing) = mpsc::unbounded_channel();
+    let hello = serde_json::to_string(&SocketMessage::Hello {}).unwrap();
+    if sink.send(Message::Text(hello)).await.is_err() {
+        return;
+    }
+    state.connections.lock().unwrap().push(sender);
+
+    loop {
+        tokio::select! {
+            message = outgoing.recv() => match message {
+                Some(message) => {
+                    if sink.send(message).await.is_err() {
+                        break;
+                    }
+                }
+                None => break,
+            },
+            message = stream.next() => match message {
+                Some(Ok(Message::Text(text))) => {
+                    let envelope_id = serde_json::from_str::<Value>(&text)
+                        .ok()
+                        .and_then(|ack| ack["envelope_id"].as_str().map(String::from));
+                    match envelope_id {
+                        Some(envelope_id) => state.acknowledged.lock().unwrap().push(envelope_id),
+                        None => warn!("Fake socket mode server received unexpected {}", text),
+                    }
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use client::models::message_body::MessageBody;
+    use client::socket_listener::{SocketModeListener, TungsteniteSocketModeListener};
+    use client::SlackClient;
+
+    #[tokio::test]
+    async fn should_identify_as_the_bot() {
+        let slack = FakeSlack::start().await;
+
+        let identity = slack.client().get_identity().await.unwrap();
+
+        assert_eq!(identity.user, BOT_NAME);
+        assert_eq!(identity.user_id, BOT_USER_ID);
+    }
+
+    #[tokio::test]
+    async fn should_record_posted_messages() {
+        let slack = FakeSlack::start().await;
+
+        let response = slack
+            .client()
+            .message_channel("C123", &MessageBody::from_text("hello"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.message.unwrap().text, "hello");
+        let posted = slack.wait_for_posted_messages(1).await;
+        assert_eq!(posted[0]["channel"], "C123");
+        assert_eq!(posted[0]["text"], "hello");
+    }
+
+    #[tokio::test]
+    async fn should_send_socket_messages_and_record_acknowledgements() {
+        let slack = FakeSlack::start().await;
+        let mut listener = TungsteniteSocketModeListener::new(Arc::new(slack.client()))
+            .await
+            .unwrap();
+
+        assert_eq!(listener.next().await.unwrap(), SocketMessage::Hello {});
+        let envelope_id = slack.send_message("C123", "U123", "hi").await;
+        let message = listener.next().await.unwrap();
+
+        assert!(
+            matches!(message, SocketMessage::Event { envelope_id: ref id, .. } if *id == envelope_id)
+        );
+        slack.wait_for_acknowledgement(&envelope_id).await;
+        assert_eq!(slack.calls_to("apps.connections.open").len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_send_unknown_events() {
+        let slack = FakeSlack::start().await;
+        let mut listener = TungsteniteSocketModeListener::new(Arc::new(slack.client()))
+            .await
+            .unwrap();
+        let raw = serde_json::json!({ "type": "brand_new_thing", "user": "U123" });
+
+        assert_eq!(listener.next().await.unwrap(), SocketMessage::Hello {});
+        slack
+            .send_event(Event::Unknown {
+                type_name: "brand_new_thing".to_string(),
+                raw: raw.clone(),
+            })
+            .await;
+        let message = listener.next().await.unwrap();
+
+        let SocketMessage::Event { payload, .. } = message else {
+            panic!("Expected an event, got {:?}", message);
+        };
+        assert_eq!(
+            payload.event,
+            Event::Unknown {
+                type_name: "brand_new_thing".to_string(),
+                raw: raw.clone(),
+            }
+        );
+        assert_eq!(payload.raw_event, raw);
+    }
+}
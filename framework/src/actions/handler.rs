@@ -1,9 +1,10 @@
-use crate::actions::Action;
+use crate::actions::{Action, FileContent};
 use async_trait::async_trait;
 use client::error::SlackClientError;
 use client::SlackClient;
 use mockall::automock;
 use std::sync::Arc;
+use tracing::info;
 
 #[async_trait]
 #[automock]
@@ -38,6 +39,84 @@ impl ActionHandler for DefaultActionHandler {
                 .message_thread(&channel, &thread_id, &message)
                 .await
                 .map(|_| ())?,
+            Action::Ephemeral {
+                channel,
+                user,
+                message,
+            } => client
+                .message_ephemeral(&channel, &user, &message)
+                .await
+                .map(|_| ())?,
+            Action::ScheduleMessage {
+                channel,
+                post_at,
+                message,
+                scheduled,
+            } => {
+                let id = client.schedule_message(&channel, post_at, &message).await?;
+                info!("Scheduled message {} in {}", id.0, channel);
+                if let Some(scheduled) = scheduled {
+                    scheduled.send(id);
+                }
+            }
+            Action::DeleteScheduledMessage {
+                channel,
+                scheduled_message_id,
+            } => client
+                .delete_scheduled_message(&channel, &scheduled_message_id)
+                .await
+                .map(|_| ())?,
+            Action::UpdateMessage {
+                channel,
+                message_id,
+                message,
+            } => client
+                .update_message(&channel, &message_id, &message)
+                .await
+                .map(|_| ())?,
+            Action::DeleteMessage {
+                channel,
+                message_id,
+            } => client
+                .delete_message(&channel, &message_id)
+                .await
+                .map(|_| ())?,
+            Action::AddReaction {
+                channel,
+                message_id,
+                emoji,
+            } => client
+                .add_reaction(&channel, &message_id, &emoji)
+                .await
+                .map(|_| ())?,
+            Action::RemoveReaction {
+                channel,
+                message_id,
+                emoji,
+            } => client
+                .remove_reaction(&channel, &message_id, &emoji)
+                .await
+                .map(|_| ())?,
+            Action::UploadFile {
+                channel,
+                thread_id,
+                filename,
+                content,
+            } => {
+                let content = match content {
+                    FileContent::Bytes(bytes) => bytes,
+                    FileContent::Path(path) => tokio::fs::read(&path).await.map_err(|err| {
+                        SlackClientError::InvalidRequest(format!(
+                            "Couldn't read file to upload {:?}: {}",
+                            path, err
+                        ))
+                    })?,
+                };
+                client
+                    .upload_file(&channel, thread_id, &filename, content)
+                    .await
+                    .map(|_| ())?
+            }
         }
 
         Ok(())
@@ -47,10 +126,13 @@ impl ActionHandler for DefaultActionHandler {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::actions::ScheduledMessageSender;
+    use client::models::file::File;
     use client::models::http_response::HttpApiResponse;
     use client::models::http_response::Message;
     use client::models::message_body::MessageBody;
     use client::models::message_id::MessageId;
+    use client::models::scheduled_message::ScheduledMessageId;
     use client::MockSlackClient;
 
     #[tokio::test]
@@ -69,7 +151,7 @@ mod tests {
                 Ok(HttpApiResponse {
                     ok: true,
                     message: Some(Message {
-                        id: "".to_string().into(),
+                        id: Some("".to_string().into()),
                         text: "".to_string(),
                         user: "".to_string(),
                     }),
@@ -105,7 +187,153 @@ mod tests {
                 Ok(HttpApiResponse {
                     ok: true,
                     message: Some(Message {
-                        id: "".to_string().into(),
+                        id: Some("".to_string().into()),
+                        text: "".to_string(),
+                        user: "".to_string(),
+                    }),
+                    error: None,
+                    errors: None,
+                })
+            });
+
+        handler
+            .handle(test_action, Arc::new(mock_client))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn given_ephemeral_action_should_send_ephemeral_message() {
+        let handler = DefaultActionHandler::default();
+        let test_action = Action::Ephemeral {
+            channel: String::from("#bots"),
+            user: String::from("U123"),
+            message: MessageBody::from_text("only for you"),
+        };
+        let mut mock_client = MockSlackClient::new();
+        mock_client
+            .expect_message_ephemeral()
+            .withf(|channel, user, message| {
+                channel == "#bots" && user == "U123" && message.get_text() == "only for you"
+            })
+            .times(1)
+            .returning(|_, _, _| {
+                Ok(HttpApiResponse {
+                    ok: true,
+                    message: None,
+                    error: None,
+                    errors: None,
+                })
+            });
+
+        handler
+            .handle(test_action, Arc::new(mock_client))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn given_schedule_message_action_should_schedule_message() {
+        let handler = DefaultActionHandler::default();
+        let test_action = Action::ScheduleMessage {
+            channel: String::from("#bots"),
+            post_at: 1792252800,
+            message: MessageBody::from_text("good morning"),
+            scheduled: None,
+        };
+        let mut mock_client = MockSlackClient::new();
+        mock_client
+            .expect_schedule_message()
+            .withf(|channel, post_at, message| {
+                channel == "#bots" && *post_at == 1792252800 && message.get_text() == "good morning"
+            })
+            .times(1)
+            .returning(|_, _, _| Ok("Q1298393284".to_string().into()));
+
+        handler
+            .handle(test_action, Arc::new(mock_client))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn given_schedule_message_action_with_sender_should_send_scheduled_message_id() {
+        let handler = DefaultActionHandler::default();
+        let (sender, receiver) = ScheduledMessageSender::channel();
+        let test_action = Action::ScheduleMessage {
+            channel: String::from("#bots"),
+            post_at: 1792252800,
+            message: MessageBody::from_text("good morning"),
+            scheduled: Some(sender),
+        };
+        let mut mock_client = MockSlackClient::new();
+        mock_client
+            .expect_schedule_message()
+            .times(1)
+            .returning(|_, _, _| Ok("Q1298393284".to_string().into()));
+
+        handler
+            .handle(test_action, Arc::new(mock_client))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            receiver.await.unwrap(),
+            ScheduledMessageId::new(String::from("Q1298393284"))
+        );
+    }
+
+    #[tokio::test]
+    async fn given_delete_scheduled_message_action_should_delete_scheduled_message() {
+        let handler = DefaultActionHandler::default();
+        let test_action = Action::DeleteScheduledMessage {
+            channel: String::from("#bots"),
+            scheduled_message_id: "Q1298393284".to_string().into(),
+        };
+        let mut mock_client = MockSlackClient::new();
+        mock_client
+            .expect_delete_scheduled_message()
+            .withf(|channel, id| {
+                channel == "#bots" && id == &ScheduledMessageId::new(String::from("Q1298393284"))
+            })
+            .times(1)
+            .returning(|_, _| {
+                Ok(HttpApiResponse {
+                    ok: true,
+                    message: None,
+                    error: None,
+                    errors: None,
+                })
+            });
+
+        handler
+            .handle(test_action, Arc::new(mock_client))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn given_update_message_action_should_update_message() {
+        let handler = DefaultActionHandler::default();
+        let test_action = Action::UpdateMessage {
+            channel: String::from("#bots"),
+            message_id: "message-id".to_string().into(),
+            message: MessageBody::from_text("hello again world"),
+        };
+        let mut mock_client = MockSlackClient::new();
+        mock_client
+            .expect_update_message()
+            .withf(|channel, message_id, message| {
+                channel == "#bots"
+                    && message_id == &MessageId::new(String::from("message-id"))
+                    && message.get_text() == "hello again world"
+            })
+            .times(1)
+            .returning(|_, _, _| {
+                Ok(HttpApiResponse {
+                    ok: true,
+                    message: Some(Message {
+                        id: Some("".to_string().into()),
                         text: "".to_string(),
                         user: "".to_string(),
                     }),
@@ -119,4 +347,181 @@ mod tests {
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    async fn given_delete_message_action_should_delete_message() {
+        let handler = DefaultActionHandler::default();
+        let test_action = Action::DeleteMessage {
+            channel: String::from("#bots"),
+            message_id: "message-id".to_string().into(),
+        };
+        let mut mock_client = MockSlackClient::new();
+        mock_client
+            .expect_delete_message()
+            .withf(|channel, message_id| {
+                channel == "#bots" && message_id == &MessageId::new(String::from("message-id"))
+            })
+            .times(1)
+            .returning(|_, _| {
+                Ok(HttpApiResponse {
+                    ok: true,
+                    message: None,
+                    error: None,
+                    errors: None,
+                })
+            });
+
+        handler
+            .handle(test_action, Arc::new(mock_client))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn given_add_reaction_action_should_react_to_message() {
+        let handler = DefaultActionHandler::default();
+        let test_action = Action::AddReaction {
+            channel: String::from("#bots"),
+            message_id: "message-id".to_string().into(),
+            emoji: String::from("upboat"),
+        };
+        let mut mock_client = MockSlackClient::new();
+        mock_client
+            .expect_add_reaction()
+            .withf(|channel, message_id, emoji| {
+                channel == "#bots"
+                    && message_id == &MessageId::new(String::from("message-id"))
+                    && emoji == "upboat"
+            })
+            .times(1)
+            .returning(|_, _, _| {
+                Ok(HttpApiResponse {
+                    ok: true,
+                    message: None,
+                    error: None,
+                    errors: None,
+                })
+            });
+
+        handler
+            .handle(test_action, Arc::new(mock_client))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn given_remove_reaction_action_should_remove_reaction_from_message() {
+        let handler = DefaultActionHandler::default();
+        let test_action = Action::RemoveReaction {
+            channel: String::from("#bots"),
+            message_id: "message-id".to_string().into(),
+            emoji: String::from("upboat"),
+        };
+        let mut mock_client = MockSlackClient::new();
+        mock_client
+            .expect_remove_reaction()
+            .withf(|channel, message_id, emoji| {
+                channel == "#bots"
+                    && message_id == &MessageId::new(String::from("message-id"))
+                    && emoji == "upboat"
+            })
+            .times(1)
+            .returning(|_, _, _| {
+                Ok(HttpApiResponse {
+                    ok: true,
+                    message: None,
+                    error: None,
+                    errors: None,
+                })
+            });
+
+        handler
+            .handle(test_action, Arc::new(mock_client))
+            .await
+            .unwrap();
+    }
+
+    fn uploaded_file() -> File {
+        File {
+            id: "F123".to_string(),
+            name: Some("karma.csv".to_string()),
+            title: Some("karma.csv".to_string()),
+            mimetype: None,
+            filetype: None,
+            size: None,
+            url_private: None,
+            permalink: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn given_upload_file_action_with_bytes_should_upload_file() {
+        let handler = DefaultActionHandler::default();
+        let test_action = Action::UploadFile {
+            channel: String::from("C123"),
+            thread_id: Some("thread-id".to_string().into()),
+            filename: String::from("karma.csv"),
+            content: FileContent::Bytes(b"sam,3".to_vec()),
+        };
+        let mut mock_client = MockSlackClient::new();
+        mock_client
+            .expect_upload_file()
+            .withf(|channel, thread, filename, content| {
+                channel == "C123"
+                    && thread == &Some(MessageId::new(String::from("thread-id")))
+                    && filename == "karma.csv"
+                    && content == b"sam,3"
+            })
+            .times(1)
+            .returning(|_, _, _, _| Ok(uploaded_file()));
+
+        handler
+            .handle(test_action, Arc::new(mock_client))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn given_upload_file_action_with_path_should_upload_file_contents() {
+        let handler = DefaultActionHandler::default();
+        let test_action = Action::UploadFile {
+            channel: String::from("C123"),
+            thread_id: None,
+            filename: String::from("README.md"),
+            content: FileContent::Path([env!("CARGO_MANIFEST_DIR"), "README.md"].iter().collect()),
+        };
+        let mut mock_client = MockSlackClient::new();
+        mock_client
+            .expect_upload_file()
+            .withf(|channel, thread, filename, content| {
+                channel == "C123"
+                    && thread.is_none()
+                    && filename == "README.md"
+                    && content == include_bytes!("../../README.md")
+            })
+            .times(1)
+            .returning(|_, _, _, _| Ok(uploaded_file()));
+
+        handler
+            .handle(test_action, Arc::new(mock_client))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn given_upload_file_action_with_missing_path_should_return_error() {
+        let handler = DefaultActionHandler::default();
+        let test_action = Action::UploadFile {
+            channel: String::from("C123"),
+            thread_id: None,
+            filename: String::from("missing.csv"),
+            content: FileContent::Path("does/not/exist.csv".into()),
+        };
+        let mut mock_client = MockSlackClient::new();
+        mock_client.expect_upload_file().times(0);
+
+        let result = handler.handle(test_action, Arc::new(mock_client)).await;
+
+        assert!(matches!(result, Err(SlackClientError::InvalidRequest(_))));
+    }
 }
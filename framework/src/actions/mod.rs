@@ -1,9 +1,13 @@
 use client::models::message_body::MessageBody;
 use client::models::message_id::MessageId;
+use client::models::scheduled_message::ScheduledMessageId;
+use std::fmt;
+use std::path::PathBuf;
+use tokio::sync::oneshot;
 
 pub mod handler;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug)]
 pub enum Action {
     MessageChannel {
         channel: String,
@@ -14,4 +18,79 @@ pub enum Action {
         thread_id: MessageId,
         message: MessageBody,
     },
+    /// A message only `user` can see
+    Ephemeral {
+        channel: String,
+        user: String,
+        message: MessageBody,
+    },
+    /// Send a message at `post_at`, a Unix timestamp
+    ///
+    /// If `scheduled` is set, the ID of the scheduled message is sent to it so that it can be
+    /// cancelled later with [`Action::DeleteScheduledMessage`].
+    ScheduleMessage {
+        channel: String,
+        post_at: u64,
+        message: MessageBody,
+        scheduled: Option<ScheduledMessageSender>,
+    },
+    /// Cancel a message scheduled with [`Action::ScheduleMessage`] before it is sent
+    DeleteScheduledMessage {
+        channel: String,
+        scheduled_message_id: ScheduledMessageId,
+    },
+    UpdateMessage {
+        channel: String,
+        message_id: MessageId,
+        message: MessageBody,
+    },
+    DeleteMessage {
+        channel: String,
+        message_id: MessageId,
+    },
+    AddReaction {
+        channel: String,
+        message_id: MessageId,
+        emoji: String,
+    },
+    RemoveReaction {
+        channel: String,
+        message_id: MessageId,
+        emoji: String,
+    },
+    UploadFile {
+        channel: String,
+        thread_id: Option<MessageId>,
+        filename: String,
+        content: FileContent,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+pub enum FileContent {
+    Bytes(Vec<u8>),
+    /// Read from disk when the action is handled
+    Path(PathBuf),
+}
+
+/// Sends the ID of a scheduled message back to the plugin that scheduled it, once Slack has
+/// accepted it
+pub struct ScheduledMessageSender(oneshot::Sender<ScheduledMessageId>);
+
+impl ScheduledMessageSender {
+    pub fn channel() -> (Self, oneshot::Receiver<ScheduledMessageId>) {
+        let (sender, receiver) = oneshot::channel();
+        (ScheduledMessageSender(sender), receiver)
+    }
+
+    pub(crate) fn send(self, id: ScheduledMessageId) {
+        // The plugin may have stopped waiting for the ID, which isn't an error
+        let _ = self.0.send(id);
+    }
+}
+
+impl fmt::Debug for ScheduledMessageSender {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ScheduledMessageSender")
+    }
 }
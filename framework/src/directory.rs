@@ -0,0 +1,433 @@
+use client::models::channel::Channel;
+use client::models::socket_message::Event;
+use client::models::user::User;
+use client::SlackClient;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tracing::{debug, error};
+
+/// Resolves user and channel IDs to names and names to IDs, caching the results.
+///
+/// Register it with `SlackBot::with_directory` and get it in plugins with
+/// `dependencies.get::<Directory>()`. Cached entries expire after the configured TTL, and are
+/// invalidated early when Slack tells us a user or channel has changed.
+pub struct Directory {
+    client: Arc<dyn SlackClient + Send + Sync>,
+    ttl: Duration,
+    cache: Mutex<Cache>,
+}
+
+struct Cached<T> {
+    value: T,
+    fetched_at: Instant,
+}
+
+#[derive(Default)]
+struct Cache {
+    users: HashMap<String, Cached<User>>,
+    channels: HashMap<String, Cached<Channel>>,
+    user_ids_by_name: Option<Cached<HashMap<String, String>>>,
+    channel_ids_by_name: Option<Cached<HashMap<String, String>>>,
+}
+
+impl<T: Clone> Cached<T> {
+    fn new(value: T) -> Self {
+        Self {
+            value,
+            fetched_at: Instant::now(),
+        }
+    }
+
+    fn get_fresh(&self, ttl: Duration) -> Option<T> {
+        if self.fetched_at.elapsed() < ttl {
+            Some(self.value.clone())
+        } else {
+            None
+        }
+    }
+}
+
+impl Directory {
+    pub fn new(client: Arc<dyn SlackClient + Send + Sync>, ttl: Duration) -> Self {
+        Self {
+            client,
+            ttl,
+            cache: Mutex::new(Cache::default()),
+        }
+    }
+
+    /// Get a user by their ID
+    pub async fn user(&self, user_id: &str) -> Option<User> {
+        let cached = self
+            .cache
+            .lock()
+            .await
+            .users
+            .get(user_id)
+            .and_then(|user| user.get_fresh(self.ttl));
+        if cached.is_some() {
+            return cached;
+        }
+
+        match self.client.get_user(user_id).await {
+            Ok(user) => {
+                self.cache
+                    .lock()
+                    .await
+                    .users
+                    .insert(user_id.to_string(), Cached::new(user.clone()));
+                Some(user)
+            }
+            Err(err) => {
+                error!("Failed to look up user {}: {:?}", user_id, err);
+                None
+            }
+        }
+    }
+
+    /// Get the name Slack displays for a user
+    pub async fn user_name(&self, user_id: &str) -> Option<String> {
+        self.user(user_id)
+            .await
+            .map(|user| user.display_name().to_string())
+    }
+
+    /// Find a user's ID from their username or display name, with or without a leading `@`
+    pub async fn user_id(&self, name: &str) -> Option<String> {
+        let name = name.trim_start_matches('@');
+        let cached = self
+            .cache
+            .lock()
+            .await
+            .user_ids_by_name
+            .as_ref()
+            .and_then(|index| index.get_fresh(self.ttl));
+        let index = match cached {
+            Some(index) => index,
+            None => self.fetch_user_index().await?,
+        };
+
+        index.get(name).cloned()
+    }
+
+    /// Get a channel by its ID
+    pub async fn channel(&self, channel_id: &str) -> Option<Channel> {
+        let cached = self
+            .cache
+            .lock()
+            .await
+            .channels
+            .get(channel_id)
+            .and_then(|channel| channel.get_fresh(self.ttl));
+        if cached.is_some() {
+            return cached;
+        }
+
+        match self.client.get_channel(channel_id).await {
+            Ok(channel) => {
+                self.cache
+                    .lock()
+                    .await
+                    .channels
+                    .insert(channel_id.to_string(), Cached::new(channel.clone()));
+                Some(channel)
+            }
+            Err(err) => {
+                error!("Failed to look up channel {}: {:?}", channel_id, err);
+                None
+            }
+        }
+    }
+
+    /// Get a channel's name, without the leading `#`. Direct messages have no name.
+    pub async fn channel_name(&self, channel_id: &str) -> Option<String> {
+        self.channel(channel_id)
+            .await
+            .and_then(|channel| channel.name)
+    }
+
+    /// Find a channel's ID from its name, with or without a leading `#`
+    pub async fn channel_id(&self, name: &str) -> Option<String> {
+        let name = name.trim_start_matches('#');
+        let cached = self
+            .cache
+            .lock()
+            .await
+            .channel_ids_by_name
+            .as_ref()
+            .and_then(|index| index.get_fresh(self.ttl));
+        let index = match cached {
+            Some(index) => index,
+            None => self.fetch_channel_index().await?,
+        };
+
+        index.get(name).cloned()
+    }
+
+    /// Drop anything from the cache that the event says is out of date
+    pub async fn handle_event(&self, event: &Event) {
+        let mut cache = self.cache.lock().await;
+        match event {
+            Event::UserChange(change) => {
+                debug!("Invalidating cached user {}", change.user.id);
+                cache.users.remove(&change.user.id);
+                cache.user_ids_by_name = None;
+            }
+            Event::ChannelRename(rename) => {
+                debug!("Invalidating cached channel {}", rename.channel.id);
+                cache.channels.remove(&rename.channel.id);
+                cache.channel_ids_by_name = None;
+            }
+            Event::ChannelCreated(_) => {
+                cache.channel_ids_by_name = None;
+            }
+            Event::ChannelArchive(archive) | Event::ChannelUnarchive(archive) => {
+                debug!("Invalidating cached channel {}", archive.channel);
+                cache.channels.remove(&archive.channel);
+            }
+            Event::ChannelDeleted(deleted) => {
+                debug!("Invalidating cached channel {}", deleted.channel);
+                cache.channels.remove(&deleted.channel);
+                cache.channel_ids_by_name = None;
+            }
+            Event::TeamJoin(_) => {
+                cache.user_ids_by_name = None;
+            }
+            _ => {}
+        }
+    }
+
+    async fn fetch_user_index(&self) -> Option<HashMap<String, String>> {
+        let mut index = HashMap::new();
+        let mut cursor = None;
+        loop {
+            let page = match self.client.list_users(cursor).await {
+                Ok(page) => page,
+                Err(err) => {
+                    error!("Failed to list users: {:?}", err);
+                    return None;
+                }
+            };
+            for user in page.users {
+                index.insert(user.display_name().to_string(), user.id.clone());
+                index.insert(user.name.clone(), user.id.clone());
+            }
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        self.cache.lock().await.user_ids_by_name = Some(Cached::new(index.clone()));
+        Some(index)
+    }
+
+    async fn fetch_channel_index(&self) -> Option<HashMap<String, String>> {
+        let mut index = HashMap::new();
+        let mut cursor = None;
+        loop {
+            let page = match self.client.list_channels(cursor).await {
+                Ok(page) => page,
+                Err(err) => {
+                    error!("Failed to list channels: {:?}", err);
+                    return None;
+                }
+            };
+            for channel in page.channels {
+                if let Some(name) = channel.name {
+                    index.insert(name, channel.id);
+                }
+            }
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        self.cache.lock().await.channel_ids_by_name = Some(Cached::new(index.clone()));
+        Some(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use client::models::channel::ChannelPage;
+    use client::models::socket_message::{
+        ChannelDeletedEvent, ChannelRenameEvent, UserChangeEvent,
+    };
+    use client::MockSlackClient;
+
+    const TTL: Duration = Duration::from_secs(60);
+
+    fn user(id: &str, name: &str) -> User {
+        User {
+            id: id.to_string(),
+            name: name.to_string(),
+            real_name: None,
+            deleted: false,
+            is_bot: false,
+            profile: None,
+        }
+    }
+
+    fn channel(id: &str, name: &str) -> Channel {
+        Channel {
+            id: id.to_string(),
+            name: Some(name.to_string()),
+            created: None,
+            creator: None,
+            is_private: false,
+            is_archived: false,
+            is_im: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn should_cache_user_lookups() {
+        let mut client = MockSlackClient::new();
+        client
+            .expect_get_user()
+            .withf(|id| id == "U123")
+            .times(1)
+            .returning(|_| Ok(user("U123", "sam")));
+        let directory = Directory::new(Arc::new(client), TTL);
+
+        assert_eq!(directory.user_name("U123").await, Some("sam".to_string()));
+        assert_eq!(directory.user_name("U123").await, Some("sam".to_string()));
+    }
+
+    #[tokio::test]
+    async fn given_expired_cache_should_look_up_user_again() {
+        let mut client = MockSlackClient::new();
+        client
+            .expect_get_user()
+            .times(2)
+            .returning(|_| Ok(user("U123", "sam")));
+        let directory = Directory::new(Arc::new(client), Duration::ZERO);
+
+        directory.user_name("U123").await;
+        directory.user_name("U123").await;
+    }
+
+    #[tokio::test]
+    async fn given_user_change_event_should_look_up_user_again() {
+        let mut client = MockSlackClient::new();
+        client
+            .expect_get_user()
+            .times(1)
+            .returning(|_| Ok(user("U123", "sam")));
+        client
+            .expect_get_user()
+            .times(1)
+            .returning(|_| Ok(user("U123", "samuel")));
+        let directory = Directory::new(Arc::new(client), TTL);
+
+        assert_eq!(directory.user_name("U123").await, Some("sam".to_string()));
+        directory
+            .handle_event(&Event::UserChange(UserChangeEvent {
+                user: user("U123", "samuel"),
+            }))
+            .await;
+        assert_eq!(
+            directory.user_name("U123").await,
+            Some("samuel".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn should_resolve_channel_name_across_pages() {
+        let mut client = MockSlackClient::new();
+        client
+            .expect_list_channels()
+            .withf(|cursor| cursor.is_none())
+            .times(1)
+            .returning(|_| {
+                Ok(ChannelPage {
+                    channels: vec![channel("C1", "random")],
+                    next_cursor: Some("page-2".to_string()),
+                })
+            });
+        client
+            .expect_list_channels()
+            .withf(|cursor| cursor.as_deref() == Some("page-2"))
+            .times(1)
+            .returning(|_| {
+                Ok(ChannelPage {
+                    channels: vec![channel("C2", "general")],
+                    next_cursor: None,
+                })
+            });
+        let directory = Directory::new(Arc::new(client), TTL);
+
+        assert_eq!(
+            directory.channel_id("#general").await,
+            Some("C2".to_string())
+        );
+        assert_eq!(directory.channel_id("random").await, Some("C1".to_string()));
+    }
+
+    #[tokio::test]
+    async fn given_channel_rename_event_should_list_channels_again() {
+        let mut client = MockSlackClient::new();
+        client.expect_list_channels().times(1).returning(|_| {
+            Ok(ChannelPage {
+                channels: vec![channel("C1", "general")],
+                next_cursor: None,
+            })
+        });
+        client.expect_list_channels().times(1).returning(|_| {
+            Ok(ChannelPage {
+                channels: vec![channel("C1", "announcements")],
+                next_cursor: None,
+            })
+        });
+        let directory = Directory::new(Arc::new(client), TTL);
+
+        assert_eq!(
+            directory.channel_id("general").await,
+            Some("C1".to_string())
+        );
+        directory
+            .handle_event(&Event::ChannelRename(ChannelRenameEvent {
+                channel: channel("C1", "announcements"),
+            }))
+            .await;
+        assert_eq!(directory.channel_id("general").await, None);
+        assert_eq!(
+            directory.channel_id("announcements").await,
+            Some("C1".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn given_channel_deleted_event_should_list_channels_again() {
+        let mut client = MockSlackClient::new();
+        client.expect_list_channels().times(1).returning(|_| {
+            Ok(ChannelPage {
+                channels: vec![channel("C1", "general")],
+                next_cursor: None,
+            })
+        });
+        client.expect_list_channels().times(1).returning(|_| {
+            Ok(ChannelPage {
+                channels: vec![],
+                next_cursor: None,
+            })
+        });
+        let directory = Directory::new(Arc::new(client), TTL);
+
+        assert_eq!(
+            directory.channel_id("general").await,
+            Some("C1".to_string())
+        );
+        directory
+            .handle_event(&Event::ChannelDeleted(ChannelDeletedEvent {
+                channel: "C1".to_string(),
+            }))
+            .await;
+        assert_eq!(directory.channel_id("general").await, None);
+    }
+}
@@ -1,3 +1,5 @@
+use client::models::message_id::MessageId;
+
 #[derive(Debug, PartialEq)]
 pub struct CommandData {
     /// The parsed command (first word after bot mention)
@@ -12,7 +14,27 @@ pub struct CommandData {
     pub user: String,
 }
 
+/// Someone added or removed a reaction to a message
+#[derive(Debug, PartialEq)]
+pub struct ReactionData {
+    /// The emoji's name, without colons or skin tone, e.g. `thumbsup`
+    pub emoji: String,
+    /// The skin tone, from 2 to 6, if one was chosen
+    pub skin_tone: Option<u8>,
+    /// `false` if the reaction was removed
+    pub added: bool,
+    /// The user who reacted
+    pub user: String,
+    /// The channel of the message that was reacted to
+    pub channel: String,
+    /// The message that was reacted to
+    pub message: MessageId,
+    /// The user who posted the message, if Slack said
+    pub message_user: Option<String>,
+}
+
 #[derive(Debug, PartialEq)]
 pub enum EnrichedEvent {
     Command(CommandData),
+    Reaction(ReactionData),
 }
@@ -1,31 +1,101 @@
-use crate::enriched_event::{CommandData, EnrichedEvent};
-use client::models::socket_message::{Event, MessageEvent};
+use crate::enriched_event::{CommandData, EnrichedEvent, ReactionData};
+use client::models::message_id::MessageId;
+use client::models::socket_message::{
+    AppMentionEvent, Event, MessageEvent, ReactionEvent, ReactionItem,
+};
+use client::recent_ids::RecentIds;
+use std::sync::Mutex;
+
+/// How many commands to remember, to recognise the same message arriving as both a message and an
+/// app mention. Slack sends both within moments of each other.
+const RECENT_COMMANDS: usize = 100;
 
 pub struct EventProcessor {
     bot_name: String,
     bot_id: String,
+    /// Channel and `ts` of the messages most recently turned into commands
+    recent_commands: Mutex<RecentIds<(String, MessageId)>>,
 }
 
 impl EventProcessor {
     pub fn new(bot_name: String, bot_id: String) -> Self {
-        Self { bot_name, bot_id }
+        Self {
+            bot_name,
+            bot_id,
+            recent_commands: Mutex::new(RecentIds::with_capacity(RECENT_COMMANDS)),
+        }
     }
 
-    /// Process a raw Event into an EnrichedEvent if the bot is being addressed
+    /// Process a raw Event into an EnrichedEvent if the bot is being addressed, or if it's a
+    /// reaction to a message
     pub fn process(&self, event: &Event) -> Option<EnrichedEvent> {
         match event {
-            Event::Message(msg_event) => self.process_message(msg_event),
+            Event::Message(msg_event) if msg_event.is_new_message() => {
+                self.process_message(msg_event)
+            }
+            Event::AppMention(mention) => self.process_app_mention(mention),
+            Event::ReactionAdded(reaction) => Self::process_reaction(reaction, true),
+            Event::ReactionRemoved(reaction) => Self::process_reaction(reaction, false),
             _ => None,
         }
     }
 
+    fn process_reaction(reaction: &ReactionEvent, added: bool) -> Option<EnrichedEvent> {
+        // Reactions to files don't belong to a channel, so there's nothing to route them by
+        let ReactionItem::Message { channel, ts } = &reaction.item else {
+            return None;
+        };
+
+        // Slack sends skin toned reactions as e.g. `thumbsup::skin-tone-2`
+        let (emoji, skin_tone) = match reaction.reaction.split_once("::skin-tone-") {
+            Some((emoji, tone)) => (emoji.to_string(), tone.parse().ok()),
+            None => (reaction.reaction.clone(), None),
+        };
+
+        Some(EnrichedEvent::Reaction(ReactionData {
+            emoji,
+            skin_tone,
+            added,
+            user: reaction.user.clone(),
+            channel: channel.clone(),
+            message: ts.clone(),
+            message_user: reaction.item_user.clone(),
+        }))
+    }
+
     fn process_message(&self, msg_event: &MessageEvent) -> Option<EnrichedEvent> {
         let text = msg_event.text.as_ref()?;
+        let channel = msg_event.channel.clone().unwrap_or_else(|| {
+            // For DMs, channel might be in a different field
+            msg_event.channel_type.clone().unwrap_or_default()
+        });
+        let user = msg_event.user.clone().unwrap_or_default();
+        let ts = msg_event.ts.as_ref().unwrap_or(&msg_event.id);
+
+        self.process_command(text, channel, user, ts)
+    }
+
+    fn process_app_mention(&self, mention: &AppMentionEvent) -> Option<EnrichedEvent> {
+        self.process_command(
+            &mention.text,
+            mention.channel.clone(),
+            mention.user.clone(),
+            &mention.ts,
+        )
+    }
+
+    fn process_command(
+        &self,
+        text: &str,
+        channel: String,
+        user: String,
+        ts: &MessageId,
+    ) -> Option<EnrichedEvent> {
         let trimmed = text.trim();
 
         let (is_addressed, remaining_text) = self.is_bot_addressed(trimmed);
         let parts: Vec<&str> = remaining_text.split_whitespace().collect();
-        if !is_addressed || parts.is_empty() {
+        if !is_addressed || parts.is_empty() || self.seen_before(&channel, ts) {
             None
         } else {
             let command = parts[0].to_lowercase();
@@ -35,11 +105,6 @@ impl EventProcessor {
             } else {
                 String::new()
             };
-            let channel = msg_event.channel.clone().unwrap_or_else(|| {
-                // For DMs, channel might be in a different field
-                msg_event.channel_type.clone().unwrap_or_default()
-            });
-            let user = msg_event.user.clone().unwrap_or_default();
 
             Some(EnrichedEvent::Command(CommandData {
                 command,
@@ -51,6 +116,15 @@ impl EventProcessor {
         }
     }
 
+    /// Whether a command has already come from this message, remembering it if not
+    fn seen_before(&self, channel: &str, ts: &MessageId) -> bool {
+        !self
+            .recent_commands
+            .lock()
+            .expect("recent commands lock poisoned")
+            .insert((channel.to_string(), ts.clone()))
+    }
+
     fn is_bot_addressed<'a>(&self, text: &'a str) -> (bool, &'a str) {
         // Check for @bot_id mention (e.g., <@U123456>)
         let mention_prefix = format!("<@{}>", self.bot_id);
@@ -85,6 +159,7 @@ impl EventProcessor {
 mod tests {
     use super::*;
     use client::models::message_id::MessageId;
+    use client::models::socket_message::{MessageSnapshot, MessageSubtype};
 
     fn create_test_processor() -> EventProcessor {
         EventProcessor::new("testbot".to_string(), "U123456".to_string())
@@ -92,12 +167,12 @@ mod tests {
 
     fn create_test_message(text: &str) -> MessageEvent {
         MessageEvent {
-            id: MessageId("test_id".to_string()),
             text: Some(text.to_string()),
             user: Some("U789".to_string()),
             blocks: Some(vec![]),
             channel: Some("#general".to_string()),
             channel_type: Some("channel".to_string()),
+            ..MessageEvent::new(MessageId("test_id".to_string()))
         }
     }
 
@@ -105,7 +180,7 @@ mod tests {
     fn should_process_direct_mention() {
         let processor = create_test_processor();
         let msg = create_test_message("<@U123456> hello world");
-        let event = Event::Message(msg);
+        let event = Event::Message(Box::new(msg));
 
         let result = processor.process(&event);
         assert!(result.is_some());
@@ -123,7 +198,7 @@ mod tests {
     fn should_process_at_name_mention() {
         let processor = create_test_processor();
         let msg = create_test_message("@testbot deploy production");
-        let event = Event::Message(msg);
+        let event = Event::Message(Box::new(msg));
 
         let result = processor.process(&event);
         assert!(result.is_some());
@@ -140,7 +215,7 @@ mod tests {
     fn should_process_name_with_colon() {
         let processor = create_test_processor();
         let msg = create_test_message("testbot: status check");
-        let event = Event::Message(msg);
+        let event = Event::Message(Box::new(msg));
 
         let result = processor.process(&event);
         assert!(result.is_some());
@@ -157,7 +232,7 @@ mod tests {
     fn should_ignore_non_addressed_message() {
         let processor = create_test_processor();
         let msg = create_test_message("just a regular message");
-        let event = Event::Message(msg);
+        let event = Event::Message(Box::new(msg));
 
         let result = processor.process(&event);
         assert!(result.is_none());
@@ -167,7 +242,7 @@ mod tests {
     fn should_parse_command_with_multiple_args() {
         let processor = create_test_processor();
         let msg = create_test_message("@testbot remind me to check the logs tomorrow");
-        let event = Event::Message(msg);
+        let event = Event::Message(Box::new(msg));
 
         let result = processor.process(&event);
         assert!(result.is_some());
@@ -188,7 +263,7 @@ mod tests {
     fn should_ignore_mid_message_mention() {
         let processor = create_test_processor();
         let msg = create_test_message("hey @testbot how are you");
-        let event = Event::Message(msg);
+        let event = Event::Message(Box::new(msg));
 
         let result = processor.process(&event);
         assert!(result.is_none());
@@ -198,7 +273,7 @@ mod tests {
     fn should_parse_command_with_no_args() {
         let processor = create_test_processor();
         let msg = create_test_message("@testbot help");
-        let event = Event::Message(msg);
+        let event = Event::Message(Box::new(msg));
 
         let result = processor.process(&event);
         assert!(result.is_some());
@@ -216,7 +291,7 @@ mod tests {
     fn should_ignore_empty_message_after_mention() {
         let processor = create_test_processor();
         let msg = create_test_message("@testbot ");
-        let event = Event::Message(msg);
+        let event = Event::Message(Box::new(msg));
 
         let result = processor.process(&event);
         assert!(result.is_none());
@@ -226,7 +301,7 @@ mod tests {
     fn should_parse_case_insensitive_command() {
         let processor = create_test_processor();
         let msg = create_test_message("@testbot DEPLOY Production");
-        let event = Event::Message(msg);
+        let event = Event::Message(Box::new(msg));
 
         let result = processor.process(&event);
         assert!(result.is_some());
@@ -238,4 +313,159 @@ mod tests {
             panic!("Expected Command variant");
         }
     }
+
+    fn create_test_mention(text: &str) -> AppMentionEvent {
+        AppMentionEvent {
+            id: MessageId("test_id".to_string()),
+            ts: MessageId("test_id".to_string()),
+            thread_ts: None,
+            text: text.to_string(),
+            user: "U789".to_string(),
+            team: None,
+            blocks: None,
+            channel: "#general".to_string(),
+        }
+    }
+
+    #[test]
+    fn should_process_app_mention() {
+        let processor = create_test_processor();
+        let event = Event::AppMention(create_test_mention("<@U123456> karma list"));
+
+        let result = processor.process(&event);
+
+        assert_eq!(
+            result,
+            Some(EnrichedEvent::Command(CommandData {
+                command: "karma".to_string(),
+                args: vec!["list".to_string()],
+                raw_args: "list".to_string(),
+                channel: "#general".to_string(),
+                user: "U789".to_string(),
+            }))
+        );
+    }
+
+    #[test]
+    fn given_message_and_app_mention_for_same_message_should_process_once() {
+        let processor = create_test_processor();
+        let message = Event::Message(Box::new(create_test_message("<@U123456> karma list")));
+        let mention = Event::AppMention(create_test_mention("<@U123456> karma list"));
+
+        assert!(processor.process(&message).is_some());
+        assert!(processor.process(&mention).is_none());
+    }
+
+    #[test]
+    fn given_app_mention_before_message_should_process_once() {
+        let processor = create_test_processor();
+        let message = Event::Message(Box::new(create_test_message("<@U123456> karma list")));
+        let mention = Event::AppMention(create_test_mention("<@U123456> karma list"));
+
+        assert!(processor.process(&mention).is_some());
+        assert!(processor.process(&message).is_none());
+    }
+
+    #[test]
+    fn given_different_messages_should_process_both() {
+        let processor = create_test_processor();
+        let mut first = create_test_message("<@U123456> karma list");
+        first.ts = Some(MessageId("1".to_string()));
+        let mut second = create_test_message("<@U123456> karma list");
+        second.ts = Some(MessageId("2".to_string()));
+
+        assert!(processor
+            .process(&Event::Message(Box::new(first)))
+            .is_some());
+        assert!(processor
+            .process(&Event::Message(Box::new(second)))
+            .is_some());
+    }
+
+    fn create_test_reaction(item: ReactionItem) -> ReactionEvent {
+        ReactionEvent {
+            id: MessageId("test_id".to_string()),
+            user: "U789".to_string(),
+            reaction: "thumbsup".to_string(),
+            item,
+            item_user: Some("U123".to_string()),
+        }
+    }
+
+    #[test]
+    fn should_process_reaction_to_message() {
+        let processor = create_test_processor();
+        let event = Event::ReactionRemoved(create_test_reaction(ReactionItem::Message {
+            channel: "#general".to_string(),
+            ts: MessageId("1360782400.498405".to_string()),
+        }));
+
+        let result = processor.process(&event);
+
+        assert_eq!(
+            result,
+            Some(EnrichedEvent::Reaction(ReactionData {
+                emoji: "thumbsup".to_string(),
+                skin_tone: None,
+                added: false,
+                user: "U789".to_string(),
+                channel: "#general".to_string(),
+                message: MessageId("1360782400.498405".to_string()),
+                message_user: Some("U123".to_string()),
+            }))
+        );
+    }
+
+    #[test]
+    fn should_separate_skin_tone_from_reaction() {
+        let processor = create_test_processor();
+        let mut reaction = create_test_reaction(ReactionItem::Message {
+            channel: "#general".to_string(),
+            ts: MessageId("1360782400.498405".to_string()),
+        });
+        reaction.reaction = "thumbsup::skin-tone-2".to_string();
+
+        let result = processor.process(&Event::ReactionAdded(reaction));
+
+        if let Some(EnrichedEvent::Reaction(reaction)) = result {
+            assert_eq!(reaction.emoji, "thumbsup");
+            assert_eq!(reaction.skin_tone, Some(2));
+        } else {
+            panic!("Expected Reaction variant");
+        }
+    }
+
+    #[test]
+    fn should_ignore_reaction_to_file() {
+        let processor = create_test_processor();
+        let event = Event::ReactionAdded(create_test_reaction(ReactionItem::File {
+            file: "F123".to_string(),
+        }));
+
+        let result = processor.process(&event);
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn should_ignore_edited_message() {
+        let processor = create_test_processor();
+        let mut msg = create_test_message("@testbot help");
+        msg.subtype = Some(MessageSubtype::MessageChanged {
+            message: Box::new(MessageSnapshot {
+                ts: "test_id".into(),
+                thread_ts: None,
+                text: Some("@testbot help".to_string()),
+                user: Some("U789".to_string()),
+                bot_id: None,
+                blocks: None,
+                files: None,
+                edited: None,
+            }),
+            previous_message: None,
+        });
+        let event = Event::Message(Box::new(msg));
+
+        let result = processor.process(&event);
+        assert!(result.is_none());
+    }
 }
@@ -2,15 +2,17 @@ extern crate core;
 
 use crate::actions::handler::{ActionHandler, DefaultActionHandler};
 use client::error::SlackClientError;
-use client::models::socket_message::SocketMessage;
+use client::models::socket_message::{DisconnectReason, SocketMessage};
 use client::{ReqwestSlackClient, SlackClient};
 use futures::future::join_all;
 use plugins::Plugin;
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::actions::Action;
 use crate::dependencies::DependenciesBuilder;
+use crate::directory::Directory;
 use crate::event_processor::EventProcessor;
 use crate::plugins::registry::PluginRegistry;
 use client::socket_listener::{SocketModeListener, TungsteniteSocketModeListener};
@@ -19,6 +21,7 @@ use tracing::{debug, error, info};
 
 pub mod actions;
 pub mod dependencies;
+pub mod directory;
 pub mod enriched_event;
 pub mod event_processor;
 pub mod plugins;
@@ -33,7 +36,12 @@ pub struct SlackBot {
 
 impl SlackBot {
     pub fn new(bot_token: &str, app_token: &str) -> Self {
-        let client = Arc::new(ReqwestSlackClient::new(bot_token, app_token));
+        Self::from_client(Arc::new(ReqwestSlackClient::new(bot_token, app_token)))
+    }
+
+    /// Talk to Slack through `client`, e.g. one pointed somewhere else with
+    /// [`ReqwestSlackClient::with_base_url`]
+    pub fn from_client(client: Arc<dyn SlackClient + Send + Sync>) -> Self {
         Self {
             client,
             plugin_registry: PluginRegistry::new(),
@@ -62,14 +70,7 @@ impl SlackBot {
         let event_processor = EventProcessor::new(identity.user, identity.user_id);
 
         let mut listener = match self.listener {
-            None => Box::new(
-                match TungsteniteSocketModeListener::new(self.client.clone()).await {
-                    Ok(listener) => listener,
-                    Err(err) => {
-                        panic!("Failed to start socket mode listener: {:?}", err)
-                    }
-                },
-            ),
+            None => Box::new(TungsteniteSocketModeListener::new(self.client.clone()).await?),
             Some(listener) => listener,
         };
         let dependencies = self.dependencies_builder.build();
@@ -102,6 +103,9 @@ impl SlackBot {
                     envelope_id: _,
                     payload,
                 } => {
+                    if let Some(directory) = dependencies.get::<Directory>() {
+                        directory.read().await.handle_event(&payload.event).await;
+                    }
                     enriched_event = event_processor.process(&payload.event);
 
                     if let Some(ref enriched) = &enriched_event {
@@ -138,10 +142,16 @@ impl SlackBot {
                     warn!("Received a slash command message but cannot handle slash commands yet, not implemented.")
                 }
                 SocketMessage::Hello { .. } => { /* Nothing to do */ }
-                SocketMessage::Disconnect { .. } => {
-                    info!("Disconnect message received");
+                SocketMessage::Disconnect {
+                    reason: Some(DisconnectReason::LinkDisabled),
+                } => {
+                    error!("Socket mode has been disabled for this app, stopping");
                     break;
                 }
+                SocketMessage::Disconnect { reason } => {
+                    // The listener reconnects by itself
+                    info!("Disconnect message received ({:?})", reason);
+                }
             }
 
             let actions: Vec<Action> = join_all(future_actions)
@@ -162,7 +172,7 @@ impl SlackBot {
 
             for result in results {
                 if let Err(err) = result {
-                    error!("Error occurred when trying to execute action: {:?}", err);
+                    Self::report_action_error(err);
                 }
             }
         }
@@ -171,11 +181,45 @@ impl SlackBot {
         Ok(())
     }
 
+    fn report_action_error(err: SlackClientError) {
+        match err.api_error() {
+            Some("channel_not_found") | Some("not_in_channel") => {
+                warn!(
+                    "Could not execute action, the channel does not exist or the bot has not been invited to it: {}",
+                    err
+                )
+            }
+            _ => match err {
+                SlackClientError::RateLimited { retry_after } => {
+                    warn!(
+                        "Slack rate limited an action and it was dropped, retry after {:?}",
+                        retry_after
+                    )
+                }
+                _ => error!("Error occurred when trying to execute action: {}", err),
+            },
+        }
+    }
+
+    /// Receive events from `listener` instead of a socket mode connection, e.g. an
+    /// [`client::events_api_listener::EventsApiListener`]
+    pub fn with_listener(mut self, listener: Box<dyn SocketModeListener + Send + Sync>) -> Self {
+        self.listener = Some(listener);
+        self
+    }
+
     pub fn with_plugin(mut self, plugin: Box<dyn Plugin>) -> Self {
         self.plugin_registry.register(plugin);
         self
     }
 
+    /// Add a [`Directory`] for plugins to resolve user and channel names, caching results for `ttl`
+    pub fn with_directory(mut self, ttl: Duration) -> Self {
+        self.dependencies_builder
+            .add(Directory::new(self.client.clone(), ttl));
+        self
+    }
+
     pub fn with_service<T: Send + Sync + 'static>(mut self, service: T) -> Self {
         self.dependencies_builder.add(service);
         self
@@ -197,9 +241,7 @@ mod tests {
     use client::models::auth_test_response::AuthTestResponse;
 
     use client::models::message_id::MessageId;
-    use client::models::socket_message::{
-        Authorization, Event, MessageEvent, Payload, SocketMessage,
-    };
+    use client::models::socket_message::{Authorization, Event, MessageEvent, Payload};
     use client::socket_listener::SocketModeListener;
     use client::MockSlackClient;
     use plugins::MockPlugin;
@@ -209,6 +251,7 @@ mod tests {
     struct TestSocketModeListener {
         call_count: usize,
         include_bot_mention: bool,
+        disconnect_first: bool,
     }
 
     impl TestSocketModeListener {
@@ -216,11 +259,22 @@ mod tests {
             self.include_bot_mention = true;
             self
         }
+
+        fn with_disconnect_first(mut self) -> Self {
+            self.disconnect_first = true;
+            self
+        }
     }
 
     #[async_trait]
     impl SocketModeListener for TestSocketModeListener {
-        async fn next(&mut self) -> serde_json::error::Result<SocketMessage> {
+        async fn next(&mut self) -> Result<SocketMessage, SlackClientError> {
+            if self.disconnect_first {
+                self.disconnect_first = false;
+                return Ok(SocketMessage::Disconnect {
+                    reason: Some(DisconnectReason::RefreshRequested),
+                });
+            }
             self.call_count += 1;
 
             if self.call_count == 1 {
@@ -234,28 +288,52 @@ mod tests {
 
                 Ok(SocketMessage::Event {
                     envelope_id: "fake-envelope-id".to_string(),
-                    payload: Payload {
-                        event: Event::Message(MessageEvent {
-                            id: MessageId("fake-id".to_string()),
+                    payload: Box::new(Payload {
+                        event: Event::Message(Box::new(MessageEvent {
                             text,
                             user: Some("U789".to_string()),
                             blocks: Some(vec![]),
                             channel: Some("#general".to_string()),
                             channel_type: Some("channel".to_string()),
-                        }),
+                            ..MessageEvent::new(MessageId("fake-id".to_string()))
+                        })),
+                        event_id: Some("fake-event-id".to_string()),
                         authorizations: vec![Authorization {
                             user_id: "F4K3U53R1D".to_string(),
                         }],
-                    },
+                        raw_event: serde_json::Value::Null,
+                    }),
                 })
             } else {
-                Ok(SocketMessage::Disconnect {})
+                Ok(SocketMessage::Disconnect {
+                    reason: Some(DisconnectReason::LinkDisabled),
+                })
             }
         }
     }
 
     #[tokio::test]
-    async fn disconnect_after_disconnect_message_received() {
+    async fn keep_running_after_refresh_requested_disconnect() {
+        let mut mock_plugin = Box::new(MockPlugin::new());
+        mock_plugin
+            .expect_subscriptions()
+            .returning(std::vec::Vec::new);
+        mock_plugin
+            .expect_on_event()
+            .times(1)
+            .returning(|_, _| Box::pin(future::ready(vec![])));
+        let bot = SlackBot::from(
+            mock_client(),
+            Box::new(MockActionHandler::new()),
+            Box::new(TestSocketModeListener::default().with_disconnect_first()),
+        )
+        .with_plugin(mock_plugin);
+
+        bot.run().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn disconnect_after_link_disabled_message_received() {
         let bot = SlackBot::from(
             mock_client(),
             Box::new(MockActionHandler::new()),
@@ -11,10 +11,18 @@ use regex::Regex;
 /// Defines the criteria for which enriched events a plugin wants to receive
 #[derive(Debug, Clone)]
 pub struct Subscription {
-    /// Regex pattern to match against the command
+    /// Regex pattern to match against the command, or the emoji for reaction subscriptions
     pub command_pattern: Regex,
     /// Optional description of what this subscription handles (for documentation)
     pub description: Option<String>,
+    /// Which kind of enriched event this subscription is for
+    pub kind: SubscriptionKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SubscriptionKind {
+    Command,
+    Reaction,
 }
 
 impl Subscription {
@@ -24,6 +32,7 @@ impl Subscription {
             command_pattern: Regex::new(&format!("^{}$", regex::escape(command)))
                 .expect("Failed to create exact match regex"),
             description: None,
+            kind: SubscriptionKind::Command,
         }
     }
 
@@ -33,6 +42,7 @@ impl Subscription {
             command_pattern: Regex::new(&format!("^{}", regex::escape(prefix)))
                 .expect("Failed to create prefix match regex"),
             description: None,
+            kind: SubscriptionKind::Command,
         }
     }
 
@@ -41,9 +51,30 @@ impl Subscription {
         Ok(Self {
             command_pattern: Regex::new(pattern)?,
             description: None,
+            kind: SubscriptionKind::Command,
         })
     }
 
+    /// Create a subscription that matches reactions with an emoji, by name without colons, in any
+    /// skin tone
+    pub fn reaction(emoji: &str) -> Self {
+        Self {
+            command_pattern: Regex::new(&format!("^{}$", regex::escape(emoji)))
+                .expect("Failed to create reaction match regex"),
+            description: None,
+            kind: SubscriptionKind::Reaction,
+        }
+    }
+
+    /// Create a subscription that matches reactions with any emoji
+    pub fn any_reaction() -> Self {
+        Self {
+            command_pattern: Regex::new("").expect("Failed to create reaction match regex"),
+            description: None,
+            kind: SubscriptionKind::Reaction,
+        }
+    }
+
     /// Add a description to this subscription
     pub fn with_description(mut self, description: &str) -> Self {
         self.description = Some(description.to_string());
@@ -54,6 +85,18 @@ impl Subscription {
     pub fn matches(&self, command: &str) -> bool {
         self.command_pattern.is_match(command)
     }
+
+    /// Check if this subscription matches the given enriched event
+    pub fn matches_event(&self, event: &EnrichedEvent) -> bool {
+        match event {
+            EnrichedEvent::Command(cmd) => {
+                self.kind == SubscriptionKind::Command && self.matches(&cmd.command)
+            }
+            EnrichedEvent::Reaction(reaction) => {
+                self.kind == SubscriptionKind::Reaction && self.matches(&reaction.emoji)
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -97,7 +140,7 @@ macro_rules! subscriptions {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::enriched_event::{CommandData, EnrichedEvent};
+    use crate::enriched_event::{CommandData, EnrichedEvent, ReactionData};
 
     #[test]
     fn should_match_exact_subscription() {
@@ -127,6 +170,35 @@ mod tests {
         assert!(!sub.matches("stop2"));
     }
 
+    #[test]
+    fn should_match_reaction_subscription_only_to_reactions() {
+        let sub = Subscription::reaction("thumbsup");
+        let reaction = |emoji: &str| {
+            EnrichedEvent::Reaction(ReactionData {
+                emoji: emoji.to_string(),
+                skin_tone: None,
+                added: true,
+                user: "U123".to_string(),
+                channel: "#general".to_string(),
+                message: "1360782400.498405".into(),
+                message_user: None,
+            })
+        };
+        let command = EnrichedEvent::Command(CommandData {
+            command: "thumbsup".to_string(),
+            args: vec![],
+            raw_args: String::new(),
+            channel: "#general".to_string(),
+            user: "U123".to_string(),
+        });
+
+        assert!(sub.matches_event(&reaction("thumbsup")));
+        assert!(!sub.matches_event(&reaction("thumbsdown")));
+        assert!(!sub.matches_event(&command));
+        assert!(Subscription::any_reaction().matches_event(&reaction("thumbsdown")));
+        assert!(!Subscription::exact("thumbsup").matches_event(&reaction("thumbsup")));
+    }
+
     #[test]
     fn should_match_subscription_with_description() {
         let sub = Subscription::exact("help").with_description("Shows help information");
@@ -161,6 +233,7 @@ mod tests {
                         vec![]
                     }
                 }
+                _ => vec![],
             }
         }
     }
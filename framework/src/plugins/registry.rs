@@ -55,35 +55,28 @@ impl PluginRegistry {
 
     /// Find all plugins that have subscriptions matching the given enriched event
     pub fn find_matching_plugins(&self, event: &EnrichedEvent) -> Vec<&dyn Plugin> {
-        let command = match event {
-            EnrichedEvent::Command(cmd) => &cmd.command,
-        };
-
         self.plugins
             .iter()
             .filter(|entry| {
                 if entry.subscriptions.is_empty() {
-                    debug!(
-                        "Plugin has no subscriptions, skipping for command: {}",
-                        command
-                    );
+                    debug!("Plugin has no subscriptions, skipping for {:?}", event);
                     return false;
                 }
 
                 let matches = entry.subscriptions.iter().any(|sub| {
-                    let is_match = sub.matches(command);
+                    let is_match = sub.matches_event(event);
                     if is_match {
                         debug!(
-                            "Subscription matched - pattern: {}, command: {}",
+                            "Subscription matched - pattern: {}, event: {:?}",
                             sub.command_pattern.as_str(),
-                            command
+                            event
                         );
                     }
                     is_match
                 });
 
                 if !matches {
-                    debug!("No subscription matched for command: {}", command);
+                    debug!("No subscription matched for {:?}", event);
                 }
 
                 matches
@@ -126,7 +119,7 @@ mod tests {
     use super::*;
     use crate::actions::Action;
     use crate::dependencies::Dependencies;
-    use crate::enriched_event::CommandData;
+    use crate::enriched_event::{CommandData, ReactionData};
     use async_trait::async_trait;
     use client::models::socket_message::Event;
 
@@ -275,6 +268,32 @@ mod tests {
         assert_eq!(matching.len(), 2);
     }
 
+    #[test]
+    fn should_match_plugins_subscribed_to_reaction() {
+        let mut registry = PluginRegistry::new();
+        registry.register(Box::new(TestPlugin {
+            _name: "votes".to_string(),
+            subscriptions: vec![Subscription::reaction("+1"), Subscription::reaction("-1")],
+        }));
+        registry.register(Box::new(TestPlugin {
+            _name: "help_plugin".to_string(),
+            subscriptions: vec![Subscription::exact("+1")],
+        }));
+        let event = EnrichedEvent::Reaction(ReactionData {
+            emoji: "+1".to_string(),
+            skin_tone: None,
+            added: true,
+            user: "U123".to_string(),
+            channel: "#general".to_string(),
+            message: "1360782400.498405".into(),
+            message_user: None,
+        });
+
+        let matching = registry.find_matching_plugins(&event);
+
+        assert_eq!(matching.len(), 1);
+    }
+
     #[test]
     fn should_get_subscription_information() {
         let mut registry = PluginRegistry::new();
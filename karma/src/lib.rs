@@ -80,6 +80,9 @@ impl Plugin for KarmaPlugin {
         let mut results = vec![];
 
         if let Event::Message(message) = event {
+            if !message.is_new_message() {
+                return results;
+            }
             if let Some(binding) = dependencies.get_dyn::<dyn KarmaRepository + Send + Sync>() {
                 let repo = binding.read().await;
                 let text = message.text.clone().unwrap_or(String::new());
@@ -140,10 +143,17 @@ impl Plugin for KarmaPlugin {
                     }
                     _ => {
                         error!("Karma plugin: Unknown subcommand");
-                        vec![]
+                        vec![Action::Ephemeral {
+                            channel: cmd.channel.clone(),
+                            user: cmd.user.clone(),
+                            message: MessageBody::from_text(
+                                "Unknown karma command, try `karma list`",
+                            ),
+                        }]
                     }
                 }
             }
+            EnrichedEvent::Reaction(_) => vec![],
         }
     }
 
@@ -158,7 +168,9 @@ mod tests {
     use crate::services::karma_repository::KarmaRepository;
     use crate::services::karma_repository::MockKarmaRepository;
     use client::models::message_body::MessageBody;
+    use client::models::socket_message::{MessageSnapshot, MessageSubtype};
     use framework::dependencies::DependenciesBuilder;
+    use framework::enriched_event::CommandData;
     use std::future;
     use tracing_test::traced_test;
 
@@ -190,6 +202,35 @@ mod tests {
         assert_eq!(0, result.len())
     }
 
+    #[tokio::test]
+    async fn given_edited_message_should_not_change_karma() {
+        let mut dependencies_builder = DependenciesBuilder::default();
+        dependencies_builder
+            .add_dyn::<dyn KarmaRepository + Send + Sync>(Box::new(MockKarmaRepository::new()));
+        let dependencies = dependencies_builder.build();
+        let event = Event::Message(Box::new(MessageEvent {
+            text: Some("sunnydays++".to_string()),
+            subtype: Some(MessageSubtype::MessageChanged {
+                message: Box::new(MessageSnapshot {
+                    ts: "1687458843.576569".into(),
+                    thread_ts: None,
+                    text: Some("sunnydays++".to_string()),
+                    user: None,
+                    bot_id: None,
+                    blocks: None,
+                    files: None,
+                    edited: None,
+                }),
+                previous_message: None,
+            }),
+            ..MessageEvent::new("1687458843.576569".into())
+        }));
+
+        let result = KarmaPlugin::default().on_event(&event, &dependencies).await;
+
+        assert_eq!(0, result.len())
+    }
+
     #[traced_test]
     #[tokio::test]
     async fn given_repo_fails_to_get_current_karma_score_should_log_error_and_return_no_actions() {
@@ -226,13 +267,11 @@ mod tests {
         let result = KarmaPlugin::default().on_event(&event, &dependencies).await;
 
         assert_eq!(1, result.len());
-        assert_eq!(
-            &Action::MessageChannel {
-                channel: "".to_string(),
-                message: MessageBody::from_text(":upboat: sunnydays: 1"),
-            },
-            result.first().unwrap()
-        );
+        let Some(Action::MessageChannel { channel, message }) = result.first() else {
+            panic!("Expected a message to the channel");
+        };
+        assert_eq!("", channel);
+        assert_eq!(&MessageBody::from_text(":upboat: sunnydays: 1"), message);
     }
 
     #[tokio::test]
@@ -246,13 +285,11 @@ mod tests {
             .await;
 
         assert_eq!(1, result.len());
-        assert_eq!(
-            &Action::MessageChannel {
-                channel: "".to_string(),
-                message: MessageBody::from_text(":up_custom: sunnydays: 1"),
-            },
-            result.first().unwrap()
-        );
+        let Some(Action::MessageChannel { channel, message }) = result.first() else {
+            panic!("Expected a message to the channel");
+        };
+        assert_eq!("", channel);
+        assert_eq!(&MessageBody::from_text(":up_custom: sunnydays: 1"), message);
     }
 
     #[tokio::test]
@@ -264,13 +301,11 @@ mod tests {
         let result = KarmaPlugin::default().on_event(&event, &dependencies).await;
 
         assert_eq!(1, result.len());
-        assert_eq!(
-            &Action::MessageChannel {
-                channel: "".to_string(),
-                message: MessageBody::from_text(":downboat: rainydays: -1"),
-            },
-            result.first().unwrap()
-        );
+        let Some(Action::MessageChannel { channel, message }) = result.first() else {
+            panic!("Expected a message to the channel");
+        };
+        assert_eq!("", channel);
+        assert_eq!(&MessageBody::from_text(":downboat: rainydays: -1"), message);
     }
 
     #[tokio::test]
@@ -284,12 +319,13 @@ mod tests {
             .await;
 
         assert_eq!(1, result.len());
+        let Some(Action::MessageChannel { channel, message }) = result.first() else {
+            panic!("Expected a message to the channel");
+        };
+        assert_eq!("", channel);
         assert_eq!(
-            &Action::MessageChannel {
-                channel: "".to_string(),
-                message: MessageBody::from_text(":down_custom: rainydays: -1"),
-            },
-            result.first().unwrap()
+            &MessageBody::from_text(":down_custom: rainydays: -1"),
+            message
         );
     }
 
@@ -339,12 +375,41 @@ mod tests {
         let result = KarmaPlugin::default().on_event(&event, &dependencies).await;
 
         assert_eq!(1, result.len());
+        let Some(Action::MessageChannel { channel, message }) = result.first() else {
+            panic!("Expected a message to the channel");
+        };
+        assert_eq!("", channel);
+        assert_eq!(&MessageBody::from_text(":upboat: sunnydays: 1"), message);
+    }
+
+    #[tokio::test]
+    async fn given_unknown_subcommand_should_reply_only_to_user() {
+        let dependencies = DependenciesBuilder::default().build();
+        let event = EnrichedEvent::Command(CommandData {
+            command: "karma".to_string(),
+            args: vec!["wat".to_string()],
+            raw_args: "wat".to_string(),
+            channel: "#general".to_string(),
+            user: "U123".to_string(),
+        });
+
+        let result = KarmaPlugin::default()
+            .on_enriched_event(&event, &dependencies)
+            .await;
+
+        let [Action::Ephemeral {
+            channel,
+            user,
+            message,
+        }] = result.as_slice()
+        else {
+            panic!("Expected one ephemeral message, got {:?}", result);
+        };
+        assert_eq!("#general", channel);
+        assert_eq!("U123", user);
         assert_eq!(
-            &Action::MessageChannel {
-                channel: "".to_string(),
-                message: MessageBody::from_text(":upboat: sunnydays: 1"),
-            },
-            result.first().unwrap()
+            &MessageBody::from_text("Unknown karma command, try `karma list`"),
+            message
         );
     }
 }
@@ -132,7 +132,7 @@ mod tests {
         (given_reason_with_because_should_capture_reason, "sunnydays++ because they are so warm", [KarmaCapture::new("sunnydays".to_string(), true, Some("because they are so warm".to_string()))]),
         (given_reason_with_due_to_should_capture_reason, "sunnydays++ due to warmth", [KarmaCapture::new("sunnydays".to_string(), true, Some("due to warmth".to_string()))]),
         (given_multiple_karma_changes_should_capture_all, "sunnydays++ for warmth\nrainydays-- foggydays--\nrust++ for strong type systems",
-            vec![
+            [
                 KarmaCapture::new("rainydays".to_string(), false, None),
                 KarmaCapture::new("foggydays".to_string(), false, None),
                 KarmaCapture::new("sunnydays".to_string(), true, Some("for warmth".to_string())),